@@ -2,11 +2,21 @@
 
 use crate::device::chip::Chip;
 use crate::enums::UnitType;
-use crate::error::DCMIResult;
+use crate::error::{DCMIError, DCMIResult, GetDataError, LicenseError};
+use crate::structs::CustomizedKV;
 use crate::DCMI;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Initial buffer size for reading the license stored in MCU, unit: byte
+///
+/// The DCMI does not define a maximum license length, the buffer grows to the length reported by the driver
+const MCU_LICENSE_READ_LEN: usize = 1024;
+
+/// Maximum length of the card customized information, unit: byte
+pub const CUSTOMIZED_INFO_MAX_LEN: usize = 1024;
 
 /// Npu management unit
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Card<'a> {
     #[cfg_attr(not(feature = "load_dynamic"), allow(dead_code))]
     pub(crate) dcmi: &'a DCMI,
@@ -128,4 +138,216 @@ impl Card<'_> {
 
         Ok((npu_chips, mcu_chip, cpu_chip))
     }
+
+    /// Query the date when this card was powered on for the first time
+    ///
+    /// # Returns
+    /// first power-on date
+    ///
+    /// # Errors
+    /// [GetDataError::InvalidData] if the date is not recorded
+    pub fn get_first_power_on_date(&self) -> DCMIResult<SystemTime> {
+        let mut first_power_on_date = 0u32;
+
+        call_dcmi_function!(
+            dcmi_get_first_power_on_date,
+            self.dcmi.lib,
+            self.id as i32,
+            &mut first_power_on_date
+        );
+
+        if first_power_on_date == 0 {
+            return Err(GetDataError::InvalidData.into());
+        }
+
+        Ok(UNIX_EPOCH + Duration::from_secs(first_power_on_date as u64))
+    }
+
+    /// Query the license stored in the MCU of this card
+    ///
+    /// # Returns
+    /// raw license data
+    ///
+    /// # Errors
+    /// [GetDataError::InvalidData] if the driver reports a negative length,
+    /// or a length larger than the buffer even after the buffer is grown to the reported length
+    ///
+    /// # Notes
+    /// Only card with MCU support this function
+    pub fn get_license(&self) -> DCMIResult<Vec<u8>> {
+        let mut license = vec![0u8; MCU_LICENSE_READ_LEN];
+        let mut len = self.read_license(&mut license)?;
+
+        if len > license.len() {
+            license = vec![0u8; len];
+            len = self.read_license(&mut license)?;
+            if len > license.len() {
+                return Err(GetDataError::InvalidData.into());
+            }
+        }
+        license.truncate(len);
+
+        Ok(license)
+    }
+
+    /// Read the license into the buffer, returns the length reported by the driver
+    fn read_license(&self, buf: &mut [u8]) -> DCMIResult<usize> {
+        let mut len = i32::try_from(buf.len()).map_err(|_| DCMIError::InvalidParameter)?;
+
+        call_dcmi_function!(
+            dcmi_mcu_get_license_info,
+            self.dcmi.lib,
+            self.id as i32,
+            buf.as_mut_ptr() as *mut ::std::os::raw::c_char,
+            &mut len
+        );
+
+        Ok(usize::try_from(len).map_err(|_| GetDataError::InvalidData)?)
+    }
+
+    /// Install a license into the MCU of this card
+    ///
+    /// # Parameters
+    /// - license: raw license data, must not be empty
+    ///
+    /// # Errors
+    /// - [DCMIError::InvalidParameter] if the license is empty or its length does not fit the C `len` parameter
+    /// - [DCMIError::LicenseError] if the MCU refuses the license
+    ///
+    /// # Notes
+    /// Only card with MCU support this function
+    pub fn install_license(&self, license: &[u8]) -> DCMIResult<()> {
+        let len = i32::try_from(license.len()).map_err(|_| DCMIError::InvalidParameter)?;
+        if len == 0 {
+            return Err(DCMIError::InvalidParameter);
+        }
+        let mut license = license.to_vec();
+
+        try_call_dcmi_function!(
+            dcmi_mcu_set_license_info,
+            self.dcmi.lib,
+            self.id as i32,
+            license.as_mut_ptr() as *mut ::std::os::raw::c_char,
+            len
+        )
+        .map_err(license_error)
+    }
+
+    /// Query the customized information of this card
+    ///
+    /// # Returns
+    /// raw customized information, the length is reported by the driver
+    pub fn get_customized_info(&self) -> DCMIResult<Vec<u8>> {
+        let mut info = vec![0u8; CUSTOMIZED_INFO_MAX_LEN];
+        let mut len = info.len() as i32;
+
+        call_dcmi_function!(
+            dcmi_get_customized_info_api,
+            self.dcmi.lib,
+            self.id as i32,
+            info.as_mut_ptr() as *mut ::std::os::raw::c_char,
+            &mut len
+        );
+
+        if len < 0 || len as usize > info.len() {
+            return Err(GetDataError::InvalidData.into());
+        }
+        info.truncate(len as usize);
+
+        Ok(info)
+    }
+
+    /// Set the customized information of this card
+    ///
+    /// # Parameters
+    /// - info: raw customized information, must not be empty and at most [CUSTOMIZED_INFO_MAX_LEN] bytes
+    pub fn set_customized_info(&self, info: &[u8]) -> DCMIResult<()> {
+        if info.is_empty() || info.len() > CUSTOMIZED_INFO_MAX_LEN {
+            return Err(DCMIError::InvalidParameter);
+        }
+
+        call_dcmi_function!(
+            dcmi_set_customized_info_api,
+            self.dcmi.lib,
+            self.id as i32,
+            info.as_ptr() as *const ::std::os::raw::c_char,
+            info.len() as i32
+        );
+
+        Ok(())
+    }
+
+    /// Query the customized information of this card with the legacy interface
+    ///
+    /// # Returns
+    /// raw customized information, the whole [CUSTOMIZED_INFO_MAX_LEN] bytes area
+    ///
+    /// # Notes
+    /// The legacy interface does not report the length of the data, prefer [Card::get_customized_info]
+    pub fn get_card_customized_info(&self) -> DCMIResult<Vec<u8>> {
+        let mut info = vec![0u8; CUSTOMIZED_INFO_MAX_LEN];
+
+        call_dcmi_function!(
+            dcmi_get_card_customized_info,
+            self.dcmi.lib,
+            self.id as i32,
+            info.as_mut_ptr() as *mut ::std::os::raw::c_char,
+            info.len() as i32
+        );
+
+        Ok(info)
+    }
+
+    /// Set the customized information of this card with the legacy interface
+    ///
+    /// # Parameters
+    /// - info: raw customized information, must not be empty and at most [CUSTOMIZED_INFO_MAX_LEN] bytes
+    pub fn set_card_customized_info(&self, info: &[u8]) -> DCMIResult<()> {
+        if info.is_empty() || info.len() > CUSTOMIZED_INFO_MAX_LEN {
+            return Err(DCMIError::InvalidParameter);
+        }
+        let mut info = info.to_vec();
+
+        call_dcmi_function!(
+            dcmi_set_card_customized_info,
+            self.dcmi.lib,
+            self.id as i32,
+            info.as_mut_ptr() as *mut ::std::os::raw::c_char,
+            info.len() as i32
+        );
+
+        Ok(())
+    }
+
+    /// Query the key/value map stored in the customized information of this card
+    ///
+    /// # Returns
+    /// key/value map, `None` if the customized information is empty or not written by [Card::set_customized_kv]
+    pub fn get_customized_kv(&self) -> DCMIResult<Option<CustomizedKV>> {
+        Ok(CustomizedKV::decode(&self.get_customized_info()?))
+    }
+
+    /// Store a key/value map into the customized information of this card
+    ///
+    /// # Parameters
+    /// - kv: key/value map
+    ///
+    /// # Errors
+    /// [DCMIError::InvalidParameter] if the encoded map exceeds [CUSTOMIZED_INFO_MAX_LEN] bytes,
+    /// nothing will be written in this case
+    ///
+    /// # Warning
+    /// The whole customized information area will be overwritten
+    pub fn set_customized_kv(&self, kv: &CustomizedKV) -> DCMIResult<()> {
+        self.set_customized_info(&kv.encode(CUSTOMIZED_INFO_MAX_LEN)?)
+    }
+}
+
+/// Map the driver errors of installing a license to [LicenseError]
+fn license_error(err: DCMIError) -> DCMIError {
+    match err {
+        DCMIError::InvalidParameter => LicenseError::InvalidLicense.into(),
+        DCMIError::OperationNotPermitted => LicenseError::NotPermitted.into(),
+        err => err,
+    }
 }
@@ -1,14 +1,27 @@
 //! Chip of the DCMI
 
 use crate::device::card::Card;
-use crate::enums::{DeviceType, DieType, FrequencyType, HealthState, UnitType, UtilizationType};
-use crate::error::{DCMIError, DCMIResult};
+use crate::device::recovery::Recovery;
+use crate::device::sm_cipher::{SmCipher, SmIv, SmKey};
+use crate::device::user_config::{UserConfig, UserConfigItem};
+use crate::enums::{
+    Compatibility, ComputePowerType, DeviceType, DieType, FrequencyType, HbmVendor, HealthState,
+    NveLevel, OutbandChannelState, PowerState, ProductType, ResumeMode, RevocationType, UnitType,
+    UtilizationType,
+};
+use crate::error::{optional, DCMIError, DCMIResult, GetDataError, RevocationError};
 use crate::structs::{
     AICPUInfo, AICoreInfo, BoardInfo, CGroupInfo, ChipInfo, ChipPCIEErrorRate, ComponentType,
-    DieInfo, DomainPCIEInfo, ECCInfo, ELabelInfo, FlashInfo, HBMInfo, LLCPerf, ManagerSensorId,
-    MemoryInfo, PCIEInfo, SensorInfo,
+    CpuNumConfig, DeviceSwitches, DieInfo, DomainPCIEInfo, ECCInfo, ELabelInfo, FanReading,
+    FlashInfo, HBMInfo, LLCPerf, ManagerSensorId, MediaEngineUtilization, MemoryInfo, PCIEInfo,
+    SensorInfo,
 };
+use hw_dcmi_wrapper_sys::bindings as ffi;
 use std::ffi::CStr;
+use std::path::Path;
+
+/// Maximum size of the secure revocation file, unit: byte
+pub const REVOCATION_FILE_MAX_SIZE: usize = 512 * 1024;
 
 /// Chip of the DCMI
 #[derive(Debug)]
@@ -482,6 +495,79 @@ impl Chip<'_, '_> {
         Ok(check_value!(voltage)?)
     }
 
+    /// Query the fan count
+    ///
+    /// # Returns
+    /// fan count
+    ///
+    /// # Notes
+    /// Only products with fans (such as Atlas 500) support this function
+    pub fn get_fan_count(&self) -> DCMIResult<u32> {
+        let mut fan_count = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_device_fan_count,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut fan_count
+        );
+
+        Ok(u32::try_from(fan_count).map_err(|_| GetDataError::InvalidData)?)
+    }
+
+    /// Query the fan speed
+    ///
+    /// # Parameters
+    /// - fan_id: fan ID, range: `1..=fan_count`, 0 means the average speed of all fans
+    ///
+    /// # Returns
+    /// fan speed, unit: RPM
+    ///
+    /// # Errors
+    /// [GetDataError] if the fan sensor failed or reports a negative speed
+    ///
+    /// # Notes
+    /// Only products with fans (such as Atlas 500) support this function
+    pub fn get_fan_speed(&self, fan_id: u32) -> DCMIResult<u32> {
+        let mut speed = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_device_fan_speed,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            fan_id as i32,
+            &mut speed
+        );
+
+        Ok(u32::try_from(check_value!(speed)?).map_err(|_| GetDataError::InvalidData)?)
+    }
+
+    /// Query all fans
+    ///
+    /// # Returns
+    /// reading of each fan, a failed sensor is reported in [FanReading::speed] without hiding the other fans
+    ///
+    /// # Errors
+    /// Only fails if the fan count could not be queried or the driver call itself fails
+    ///
+    /// # Notes
+    /// Only products with fans (such as Atlas 500) support this function
+    pub fn get_fans(&self) -> DCMIResult<Vec<FanReading>> {
+        let mut fans = Vec::new();
+        for fan_id in 1..=self.get_fan_count()? {
+            let speed = match self.get_fan_speed(fan_id) {
+                Ok(speed) => Ok(speed),
+                Err(DCMIError::GetDataError(err)) => Err(err),
+                Err(err) => return Err(err),
+            };
+            fans.push(FanReading { fan_id, speed });
+        }
+
+        Ok(fans)
+    }
+
     /// Query the PCIE error count
     ///
     /// # Returns
@@ -552,6 +638,32 @@ impl Chip<'_, '_> {
         Ok(frequency)
     }
 
+    /// Query the rated computing power
+    ///
+    /// # Parameters
+    /// - target: computing power type
+    ///
+    /// # Returns
+    /// raw `data1` of `dsmi_computing_power_info`
+    ///
+    /// # Notes
+    /// The DCMI headers define no unit for the value, it is returned unscaled
+    /// and should not be taken as TOPS without checking the reference of the product
+    pub fn get_computing_power(&self, target: ComputePowerType) -> DCMIResult<u32> {
+        let mut computing_power: ffi::dsmi_computing_power_info = unsafe { std::mem::zeroed() };
+
+        call_dcmi_function!(
+            dcmi_get_computing_power_info,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            target.into(),
+            &mut computing_power
+        );
+
+        Ok(computing_power.data1)
+    }
+
     /// Query the HBM information
     ///
     /// # Warning
@@ -573,6 +685,24 @@ impl Chip<'_, '_> {
         Ok(hbm_info.into())
     }
 
+    /// Query the HBM vendor
+    ///
+    /// # Returns
+    /// HBM vendor, [HbmVendor::Unknown] with the raw manufacturer ID if the vendor is not known
+    pub fn get_hbm_vendor(&self) -> DCMIResult<HbmVendor> {
+        let mut hbm_product_info: ffi::dcmi_hbm_product_info = unsafe { std::mem::zeroed() };
+
+        call_dcmi_function!(
+            dcmi_get_device_hbm_product_info,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut hbm_product_info
+        );
+
+        Ok(hbm_product_info.manufacturer_id.into())
+    }
+
     /// Query the memory information
     ///
     /// # Warning
@@ -631,6 +761,30 @@ impl Chip<'_, '_> {
         Ok(utilization_rate)
     }
 
+    /// Query the media engine (DVPP) utilization
+    ///
+    /// # Returns
+    /// utilization of VDEC, VPC, VENC, JPEGE and JPEGD, unit: 1%
+    ///
+    /// # Errors
+    /// [GetDataError::InvalidData] if the driver reports a negative utilization
+    ///
+    /// # Notes
+    /// Only NPU chip support this function
+    pub fn get_media_engine_utilization(&self) -> DCMIResult<MediaEngineUtilization> {
+        let mut dvpp_ratio: ffi::dcmi_dvpp_ratio = unsafe { std::mem::zeroed() };
+
+        call_dcmi_function!(
+            dcmi_get_device_dvpp_ratio_info,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut dvpp_ratio
+        );
+
+        Ok(dvpp_ratio.try_into()?)
+    }
+
     /// Query the sensor information
     ///
     /// # Parameters
@@ -694,6 +848,92 @@ impl Chip<'_, '_> {
         Ok(board_id)
     }
 
+    /// Query the board ID through `dcmi_get_board_id`
+    ///
+    /// # Returns
+    /// board ID
+    ///
+    /// # Errors
+    /// [GetDataError::InvalidData] if the driver reports a negative board ID
+    ///
+    /// # Notes
+    /// The result may differ from [Chip::get_board_id], which uses `dcmi_get_device_board_id`,
+    /// some products only support one of the two functions
+    pub fn get_dcmi_board_id(&self) -> DCMIResult<u32> {
+        let mut board_id = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_board_id,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut board_id
+        );
+
+        Ok(u32::try_from(board_id).map_err(|_| GetDataError::InvalidData)?)
+    }
+
+    /// Query the mainboard ID
+    ///
+    /// # Returns
+    /// mainboard ID
+    pub fn get_mainboard_id(&self) -> DCMIResult<u32> {
+        let mut mainboard_id = 0u32;
+
+        call_dcmi_function!(
+            dcmi_get_mainboard_id,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut mainboard_id
+        );
+
+        Ok(mainboard_id)
+    }
+
+    /// Query the slot of the chip on the board
+    ///
+    /// # Returns
+    /// chip slot (position) ID
+    ///
+    /// # Errors
+    /// [GetDataError::InvalidData] if the driver reports a negative slot
+    pub fn get_chip_slot(&self) -> DCMIResult<u32> {
+        let mut chip_pos_id = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_device_chip_slot,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut chip_pos_id
+        );
+
+        Ok(u32::try_from(chip_pos_id).map_err(|_| GetDataError::InvalidData)?)
+    }
+
+    /// Query the product type
+    ///
+    /// # Returns
+    /// product type, [ProductType::Other] with the raw string if the product is not known
+    pub fn get_product_type(&self) -> DCMIResult<ProductType> {
+        let mut product_type = [0u8; 64];
+
+        call_dcmi_function!(
+            dcmi_get_product_type,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            product_type.as_mut_ptr() as *mut ::std::os::raw::c_char,
+            product_type.len() as i32
+        );
+
+        Ok(CStr::from_bytes_until_nul(&product_type)
+            .map_err(|_| GetDataError::InvalidData)?
+            .to_str()?
+            .into())
+    }
+
     /// Query the component count
     ///
     /// # Returns
@@ -795,4 +1035,461 @@ impl Chip<'_, '_> {
 
         Ok(llc_perf.into())
     }
+
+    /// Query whether the device sharing (multiple containers using the same device) is enabled
+    ///
+    /// # Returns
+    /// `true` if enabled
+    pub fn get_share_enable(&self) -> DCMIResult<bool> {
+        let mut enable_flag = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_device_share_enable,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut enable_flag
+        );
+
+        Ok(enable_flag != 0)
+    }
+
+    /// Enable or disable the device sharing (multiple containers using the same device)
+    ///
+    /// # Parameters
+    /// - enable: `true` to enable, `false` to disable
+    ///
+    /// # Notes
+    /// Only root user can call this function
+    pub fn set_share_enable(&self, enable: bool) -> DCMIResult<()> {
+        call_dcmi_function!(
+            dcmi_set_device_share_enable,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            enable as i32
+        );
+
+        Ok(())
+    }
+
+    /// Query whether the SSH access to the device is enabled
+    ///
+    /// # Returns
+    /// `true` if enabled
+    pub fn get_ssh_enable(&self) -> DCMIResult<bool> {
+        let mut enable_flag = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_device_ssh_enable,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut enable_flag
+        );
+
+        Ok(enable_flag != 0)
+    }
+
+    /// Query whether the P2P communication of the device is enabled
+    ///
+    /// # Returns
+    /// `true` if enabled
+    pub fn get_device_p2p_enable(&self) -> DCMIResult<bool> {
+        let mut enable_flag = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_device_p2p_enable,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut enable_flag
+        );
+
+        Ok(enable_flag != 0)
+    }
+
+    /// Query whether the P2P communication in the driver is enabled
+    ///
+    /// # Returns
+    /// `true` if enabled
+    pub fn get_p2p_enable(&self) -> DCMIResult<bool> {
+        let mut enable_flag = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_p2p_enable,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut enable_flag
+        );
+
+        Ok(enable_flag != 0)
+    }
+
+    /// Query all the device switches at once
+    ///
+    /// # Returns
+    /// snapshot of the device sharing, SSH and P2P switches, `None` for the switches the product does not support
+    pub fn get_switches(&self) -> DCMIResult<DeviceSwitches> {
+        Ok(DeviceSwitches {
+            share: optional(self.get_share_enable())?,
+            ssh: optional(self.get_ssh_enable())?,
+            device_p2p: optional(self.get_device_p2p_enable())?,
+            p2p: optional(self.get_p2p_enable())?,
+        })
+    }
+
+    /// Query the out-of-band channel state
+    ///
+    /// # Returns
+    /// whether the BMC can reach the device through the out-of-band path
+    pub fn get_outband_channel_state(&self) -> DCMIResult<OutbandChannelState> {
+        let mut channel_state = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_device_outband_channel_state,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut channel_state
+        );
+
+        Ok(channel_state.into())
+    }
+
+    /// Query the compatibility between the device firmware and the driver
+    ///
+    /// # Returns
+    /// compatibility state
+    pub fn get_compatibility(&self) -> DCMIResult<Compatibility> {
+        let mut compatibility = 0;
+
+        call_dcmi_function!(
+            dcmi_get_device_compatibility,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut compatibility
+        );
+
+        Ok(compatibility.into())
+    }
+
+    /// Check whether the device firmware and the driver are compatible
+    ///
+    /// # Returns
+    /// `true` only if the compatibility is [Compatibility::Ok], an unknown compatibility is treated as incompatible
+    pub fn is_compatible(&self) -> DCMIResult<bool> {
+        Ok(self.get_compatibility()? == Compatibility::Ok)
+    }
+
+    /// Query the number of AI CPU cores
+    ///
+    /// # Returns
+    /// number of AI CPU cores
+    pub fn get_aicpu_count(&self) -> DCMIResult<u8> {
+        let mut count_info = 0u8;
+
+        call_dcmi_function!(
+            dcmi_get_device_aicpu_count_info,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut count_info
+        );
+
+        Ok(count_info)
+    }
+
+    /// Query the split of the on-device Arm cores
+    ///
+    /// # Returns
+    /// number of control CPU, AI CPU and data CPU cores
+    pub fn get_cpu_num_config(&self) -> DCMIResult<CpuNumConfig> {
+        let mut buf = [0u8; CpuNumConfig::BUF_LEN];
+
+        call_dcmi_function!(
+            dcmi_get_device_cpu_num_config,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            buf.as_mut_ptr(),
+            buf.len() as u32
+        );
+
+        Ok(CpuNumConfig::decode(&buf))
+    }
+
+    /// Rebalance the on-device Arm cores between control CPU, AI CPU and data CPU roles
+    ///
+    /// # Parameters
+    /// - config: number of control CPU, AI CPU and data CPU cores
+    ///
+    /// # Errors
+    /// [DCMIError::InvalidParameter] if the total number of cores differs from the current configuration,
+    /// nothing will be written in this case
+    ///
+    /// # Notes
+    /// The new configuration takes effect after the chip is reset
+    pub fn set_cpu_num_config(&self, config: &CpuNumConfig) -> DCMIResult<()> {
+        if config.total() != self.get_cpu_num_config()?.total() {
+            return Err(DCMIError::InvalidParameter);
+        }
+        let mut buf = config.encode();
+
+        call_dcmi_function!(
+            dcmi_set_device_cpu_num_config,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            buf.as_mut_ptr(),
+            buf.len() as u32
+        );
+
+        Ok(())
+    }
+
+    /// Query the NVE level
+    ///
+    /// # Returns
+    /// NVE level, the log level of the device firmware, [NveLevel::Unknown] with the raw value if the level is not known
+    pub fn get_nve_level(&self) -> DCMIResult<NveLevel> {
+        let mut level = 0i32;
+
+        call_dcmi_function!(
+            dcmi_get_nve_level,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            &mut level
+        );
+
+        Ok(level.into())
+    }
+
+    /// Set the NVE level
+    ///
+    /// # Parameters
+    /// - level: NVE level, the log level of the device firmware
+    pub fn set_nve_level(&self, level: NveLevel) -> DCMIResult<()> {
+        call_dcmi_function!(
+            dcmi_set_nve_level,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            level.into()
+        );
+
+        Ok(())
+    }
+
+    /// Set the power state
+    ///
+    /// # Parameters
+    /// - state: target power state
+    /// - mode: how the chip resumes from the power state
+    /// - value: resume parameter, the delay before resuming when mode is [ResumeMode::Time], unit: 1s
+    ///
+    /// # Errors
+    /// [DCMIError::OperationNotPermitted] if the power state is not allowed on the current product
+    pub fn set_power_state(
+        &self,
+        state: PowerState,
+        mode: ResumeMode,
+        value: u32,
+    ) -> DCMIResult<()> {
+        let power_info = ffi::dcmi_power_state_info_stru {
+            type_: state.into(),
+            mode: mode.into(),
+            value,
+            reserve: [0; 8],
+        };
+
+        try_call_dcmi_function!(
+            dcmi_set_power_state,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            power_info
+        )
+        .map_err(|err| match err {
+            DCMIError::NotSupport => DCMIError::OperationNotPermitted,
+            err => err,
+        })
+    }
+
+    /// Install a secure revocation file
+    ///
+    /// # Parameters
+    /// - target: revocation file type
+    /// - data: content of the revocation file, must not be empty and at most [REVOCATION_FILE_MAX_SIZE] bytes
+    ///
+    /// # Errors
+    /// - [DCMIError::InvalidParameter] if the file size is out of range, nothing is sent to the device
+    /// - [DCMIError::RevocationError] if the device rejects the file
+    pub fn install_revocation(&self, target: RevocationType, data: &[u8]) -> DCMIResult<()> {
+        if data.is_empty() || data.len() > REVOCATION_FILE_MAX_SIZE {
+            return Err(DCMIError::InvalidParameter);
+        }
+
+        try_call_dcmi_function!(
+            dcmi_set_device_sec_revocation,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            target.into(),
+            data.as_ptr(),
+            data.len() as u32
+        )
+        .map_err(|err| match err {
+            DCMIError::InvalidParameter => RevocationError::InvalidFile.into(),
+            DCMIError::SecureFunctionFail => RevocationError::VerificationFailed.into(),
+            DCMIError::OperationNotPermitted => RevocationError::NotPermitted.into(),
+            DCMIError::FileOperateFail => RevocationError::WriteFailed.into(),
+            err => err,
+        })
+    }
+
+    /// Install a secure revocation file from the file system
+    ///
+    /// # Parameters
+    /// - target: revocation file type
+    /// - path: path of the revocation file
+    ///
+    /// # Errors
+    /// Same as [Chip::install_revocation], and [DCMIError::IoError] if the file could not be read
+    pub fn install_revocation_file(
+        &self,
+        target: RevocationType,
+        path: impl AsRef<Path>,
+    ) -> DCMIResult<()> {
+        let size = std::fs::metadata(path.as_ref())?.len();
+        if size == 0 || size > REVOCATION_FILE_MAX_SIZE as u64 {
+            return Err(DCMIError::InvalidParameter);
+        }
+
+        self.install_revocation(target, &std::fs::read(path)?)
+    }
+}
+
+impl<'a, 'b> Chip<'a, 'b>
+where
+    'b: 'a,
+{
+    /// Create a handle of the firmware recovery control
+    ///
+    /// # Returns
+    /// recovery handle, which can inspect and clear the recovery flag and the boot counter
+    pub fn recovery(&self) -> Recovery<'_, 'a, 'b> {
+        Recovery { chip: self }
+    }
+
+    /// Create a handle of the named user configuration item
+    ///
+    /// # Parameters
+    /// - name: name of the configuration item
+    ///
+    /// # Returns
+    /// user configuration handle, which can get, set and clear the raw value
+    pub fn user_config(&self, name: &str) -> DCMIResult<UserConfig<'_, 'a, 'b>> {
+        UserConfig::new(self, name)
+    }
+
+    /// Query the typed user configuration item
+    ///
+    /// # Returns
+    /// decoded value of the configuration item
+    pub fn get_user_config_item<T: UserConfigItem>(&self) -> DCMIResult<T> {
+        T::decode(&self.user_config(T::NAME)?.get(T::SIZE)?)
+    }
+
+    /// Set the typed user configuration item
+    ///
+    /// # Parameters
+    /// - value: value of the configuration item
+    pub fn set_user_config_item<T: UserConfigItem>(&self, value: &T) -> DCMIResult<()> {
+        let raw = value.encode()?;
+        if raw.len() != T::SIZE {
+            return Err(DCMIError::InvalidParameter);
+        }
+        self.user_config(T::NAME)?.set(&raw)
+    }
+
+    /// Create a SM4-CBC cipher running on the crypto engine of this chip
+    ///
+    /// # Parameters
+    /// - key: SM4 key, zeroized when the cipher is dropped
+    /// - iv: SM4 initialization vector, zeroized when the cipher is dropped
+    ///
+    /// # Returns
+    /// SM4-CBC cipher
+    pub fn sm_cipher(&self, key: SmKey, iv: SmIv) -> SmCipher<'_, 'a, 'b> {
+        SmCipher::new(self, key, iv)
+    }
+
+    /// Temporarily set the NVE level, the previous level will be restored when the guard is dropped
+    ///
+    /// # Parameters
+    /// - level: NVE level used while the guard is alive
+    ///
+    /// # Returns
+    /// guard which restores the previous NVE level on drop
+    ///
+    /// # Example
+    /// ```rust no_run
+    /// # use hw_dcmi_wrapper::DCMI;
+    /// # use hw_dcmi_wrapper::device::card::Card;
+    /// use hw_dcmi_wrapper::enums::NveLevel;
+    /// # let dcmi = DCMI::init().unwrap();
+    /// # let cards = Card::query_cards(&dcmi).unwrap();
+    /// # let (chips, _, _) = cards[0].get_chips().unwrap();
+    /// let chip = &chips[0];
+    /// {
+    ///     let _guard = chip.scoped_nve_level(NveLevel::Debug).unwrap();
+    ///     // reproduce the issue here
+    /// }
+    /// // previous NVE level restored
+    /// ```
+    pub fn scoped_nve_level(&self, level: NveLevel) -> DCMIResult<NveLevelGuard<'_, 'a, 'b>> {
+        let previous = self.get_nve_level()?;
+        self.set_nve_level(level)?;
+        Ok(NveLevelGuard {
+            chip: self,
+            previous,
+        })
+    }
+}
+
+/// Guard which restores the previous NVE level on drop
+///
+/// Use [Chip::scoped_nve_level] to create it
+#[derive(Debug)]
+pub struct NveLevelGuard<'a, 'b, 'c>
+where
+    'b: 'a,
+    'c: 'b,
+{
+    chip: &'a Chip<'b, 'c>,
+    previous: NveLevel,
+}
+
+impl NveLevelGuard<'_, '_, '_> {
+    /// Query the NVE level which will be restored
+    pub fn previous(&self) -> NveLevel {
+        self.previous
+    }
+
+    /// Restore the previous NVE level now and report the result
+    pub fn restore(self) -> DCMIResult<()> {
+        let result = self.chip.set_nve_level(self.previous);
+        std::mem::forget(self);
+        result
+    }
+}
+
+impl Drop for NveLevelGuard<'_, '_, '_> {
+    fn drop(&mut self) {
+        let _ = self.chip.set_nve_level(self.previous);
+    }
 }
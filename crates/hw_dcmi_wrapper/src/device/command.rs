@@ -0,0 +1,142 @@
+//! Typed commands over the generic device information interface
+//!
+//! Many functions of the DCMI are only reachable through `dcmi_get_device_info` and `dcmi_set_device_info`,
+//! which are addressed by a (main command, sub command) pair and exchange an untyped buffer.
+//! Each pair is described here by a type implementing [DeviceInfoCommand],
+//! use [Chip::query] and [Chip::configure] to run it.
+//!
+//! # Example
+//! ```rust no_run
+//! use hw_dcmi_wrapper::device::command::{DeviceInfoCommand, QueryCommand};
+//! use hw_dcmi_wrapper::error::DCMIResult;
+//! use hw_dcmi_wrapper_sys::bindings as ffi;
+//!
+//! /// Raw status of the PMU watchdog
+//! struct PmuWatchdogStatus;
+//!
+//! impl DeviceInfoCommand for PmuWatchdogStatus {
+//!     const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_SILS;
+//!     const SUB_CMD: u32 = ffi::DCMI_SILS_SUB_CMD_DCMI_SILS_SUB_CMD_PMUWDG_STATUS;
+//!     type Payload = u32;
+//! }
+//!
+//! impl QueryCommand for PmuWatchdogStatus {
+//!     type Output = u32;
+//!
+//!     fn decode(payload: u32) -> DCMIResult<u32> {
+//!         Ok(payload)
+//!     }
+//! }
+//!
+//! # use hw_dcmi_wrapper::DCMI;
+//! # use hw_dcmi_wrapper::device::card::Card;
+//! # use hw_dcmi_wrapper::device::chip::Chip;
+//! let dcmi = DCMI::init().unwrap();
+//! let card = Card::new_unchecked(&dcmi, 0);
+//! let chip = Chip::new_unchecked(&card, 0);
+//! let status = chip.query::<PmuWatchdogStatus>().unwrap();
+//! ```
+
+use crate::device::chip::Chip;
+use crate::error::{DCMIResult, GetDataError};
+use hw_dcmi_wrapper_sys::bindings as ffi;
+
+/// Raw payload exchanged with the device
+///
+/// # Safety
+/// The all-zero bit pattern and any bit pattern written by the driver must be a valid value of the type,
+/// which holds for integers, arrays of integers and the plain C structures of the FFI bindings
+pub unsafe trait RawPayload: Copy {}
+
+unsafe impl RawPayload for u8 {}
+unsafe impl RawPayload for u16 {}
+unsafe impl RawPayload for u32 {}
+unsafe impl RawPayload for u64 {}
+unsafe impl RawPayload for i8 {}
+unsafe impl RawPayload for i16 {}
+unsafe impl RawPayload for i32 {}
+unsafe impl RawPayload for i64 {}
+unsafe impl<T: RawPayload, const N: usize> RawPayload for [T; N] {}
+
+/// Command of the generic device information interface
+pub trait DeviceInfoCommand {
+    /// Main command, one of `dcmi_main_cmd`
+    const MAIN_CMD: u32;
+    /// Sub command, meaning depends on the main command
+    const SUB_CMD: u32;
+    /// Minimum number of bytes the driver must return for a query, defaults to the size of the payload
+    const MIN_SIZE: usize = std::mem::size_of::<Self::Payload>();
+    /// Raw payload exchanged with the device
+    type Payload: RawPayload;
+}
+
+/// Command which queries data from the device
+pub trait QueryCommand: DeviceInfoCommand {
+    /// Decoded output
+    type Output;
+
+    /// Decode the raw payload returned by the device
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output>;
+}
+
+/// Command which writes data to the device
+pub trait ConfigureCommand: DeviceInfoCommand {
+    /// Typed input
+    type Input;
+
+    /// Encode the input into the raw payload sent to the device
+    fn encode(input: &Self::Input) -> DCMIResult<Self::Payload>;
+}
+
+impl Chip<'_, '_> {
+    /// Run a query command
+    ///
+    /// # Returns
+    /// decoded output of the command
+    ///
+    /// # Errors
+    /// [GetDataError::InvalidData] if the driver returns more bytes than the payload or less than [DeviceInfoCommand::MIN_SIZE]
+    pub fn query<C: QueryCommand>(&self) -> DCMIResult<C::Output> {
+        // SAFETY: the all-zero bit pattern is valid for `RawPayload`
+        let mut payload: C::Payload = unsafe { std::mem::zeroed() };
+        let mut size = std::mem::size_of::<C::Payload>() as u32;
+
+        call_dcmi_function!(
+            dcmi_get_device_info,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            C::MAIN_CMD as ffi::dcmi_main_cmd,
+            C::SUB_CMD,
+            &mut payload as *mut C::Payload as *mut ::std::os::raw::c_void,
+            &mut size
+        );
+
+        if size as usize > std::mem::size_of::<C::Payload>() || (size as usize) < C::MIN_SIZE {
+            return Err(GetDataError::InvalidData.into());
+        }
+
+        C::decode(payload)
+    }
+
+    /// Run a configure command
+    ///
+    /// # Parameters
+    /// - input: typed input of the command
+    pub fn configure<C: ConfigureCommand>(&self, input: &C::Input) -> DCMIResult<()> {
+        let payload = C::encode(input)?;
+
+        call_dcmi_function!(
+            dcmi_set_device_info,
+            self.card.dcmi.lib,
+            self.card.id as i32,
+            self.id as i32,
+            C::MAIN_CMD as ffi::dcmi_main_cmd,
+            C::SUB_CMD,
+            &payload as *const C::Payload as *const ::std::os::raw::c_void,
+            std::mem::size_of::<C::Payload>() as u32
+        );
+
+        Ok(())
+    }
+}
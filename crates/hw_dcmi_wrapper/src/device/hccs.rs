@@ -0,0 +1,91 @@
+//! HCCS interconnect of the chip
+
+use crate::device::chip::Chip;
+use crate::device::command::{DeviceInfoCommand, QueryCommand, RawPayload};
+use crate::error::DCMIResult;
+use crate::structs::{HccsLanes, HccsStatistics, HccsStatus};
+use hw_dcmi_wrapper_sys::bindings as ffi;
+
+unsafe impl RawPayload for ffi::dcmi_hccs_statues {}
+unsafe impl RawPayload for ffi::dcmi_hccs_lane_info {}
+unsafe impl RawPayload for ffi::dcmi_hccs_statistic_info {}
+
+/// Query the HCCS PCS status
+#[derive(Debug)]
+pub struct HccsStatusCommand;
+
+impl DeviceInfoCommand for HccsStatusCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_HCCS;
+    const SUB_CMD: u32 = ffi::DCMI_HCCS_SUB_CMD_DCMI_HCCS_CMD_GET_STATUS;
+    type Payload = ffi::dcmi_hccs_statues;
+}
+
+impl QueryCommand for HccsStatusCommand {
+    type Output = HccsStatus;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload.into())
+    }
+}
+
+/// Query the HCCS lane map
+#[derive(Debug)]
+pub struct HccsLanesCommand;
+
+impl DeviceInfoCommand for HccsLanesCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_HCCS;
+    const SUB_CMD: u32 = ffi::DCMI_HCCS_SUB_CMD_DCMI_HCCS_CMD_GET_LANE_INFO;
+    type Payload = ffi::dcmi_hccs_lane_info;
+}
+
+impl QueryCommand for HccsLanesCommand {
+    type Output = HccsLanes;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload.into())
+    }
+}
+
+/// Query the HCCS traffic counters
+#[derive(Debug)]
+pub struct HccsStatisticsCommand;
+
+impl DeviceInfoCommand for HccsStatisticsCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_HCCS;
+    const SUB_CMD: u32 = ffi::DCMI_HCCS_SUB_CMD_DCMI_HCCS_CMD_GET_STATISTIC_INFO;
+    type Payload = ffi::dcmi_hccs_statistic_info;
+}
+
+impl QueryCommand for HccsStatisticsCommand {
+    type Output = HccsStatistics;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload.into())
+    }
+}
+
+impl Chip<'_, '_> {
+    /// Query the HCCS PCS status
+    ///
+    /// # Returns
+    /// HCCS status
+    pub fn get_hccs_status(&self) -> DCMIResult<HccsStatus> {
+        self.query::<HccsStatusCommand>()
+    }
+
+    /// Query the HCCS lane map
+    ///
+    /// # Returns
+    /// up/down state of each port and its lanes
+    pub fn get_hccs_lanes(&self) -> DCMIResult<HccsLanes> {
+        self.query::<HccsLanesCommand>()
+    }
+
+    /// Query the HCCS traffic counters
+    ///
+    /// # Returns
+    /// tx/rx/CRC error counters of each port, use [HccsStatistics::delta] to get the increase between two queries
+    pub fn get_hccs_statistics(&self) -> DCMIResult<HccsStatistics> {
+        self.query::<HccsStatisticsCommand>()
+    }
+}
@@ -0,0 +1,314 @@
+//! Low power (LP) subsystem of the chip
+
+use crate::device::chip::Chip;
+use crate::device::command::{ConfigureCommand, DeviceInfoCommand, QueryCommand, RawPayload};
+use crate::enums::FrequencyType;
+use crate::error::{optional, DCMIError, DCMIResult};
+use crate::structs::{
+    AiCoreFrequency, PerformanceProfile, PowerRails, RailReading, ThrottleReasons,
+};
+use hw_dcmi_wrapper_sys::bindings as ffi;
+
+unsafe impl RawPayload for ffi::dcmi_lp_power_info {}
+unsafe impl RawPayload for ffi::dcmi_lp_cur_tops_stru {}
+unsafe impl RawPayload for ffi::dcmi_lp_work_tops_stru {}
+unsafe impl RawPayload for ffi::dcmi_lp_tops_details_stru {}
+
+/// Query the voltage and current of a power domain, `SUB_CMD` is one of the `*_VOLTAGE_CURRENT` sub commands
+///
+/// # Notes
+/// The DCMI defines no structure for these sub commands, the payload is assumed to be
+/// two 32-bit words, voltage followed by current, in units the DCMI does not document.
+/// A reply of any other size is rejected with [GetDataError::InvalidData](crate::error::GetDataError::InvalidData)
+#[derive(Debug)]
+pub struct PowerRailCommand<const SUB_CMD: u32>;
+
+/// Query the voltage and current of the AI Core domain
+pub type AiCoreRailCommand =
+    PowerRailCommand<{ ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_AICORE_VOLTAGE_CURRENT }>;
+/// Query the voltage and current of the hybrid domain
+pub type HybridRailCommand =
+    PowerRailCommand<{ ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_HYBIRD_VOLTAGE_CURRENT }>;
+/// Query the voltage and current of the TaiShan (CPU) domain
+pub type TaishanRailCommand =
+    PowerRailCommand<{ ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_TAISHAN_VOLTAGE_CURRENT }>;
+/// Query the voltage and current of the DDR domain
+pub type DdrRailCommand =
+    PowerRailCommand<{ ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_DDR_VOLTAGE_CURRENT }>;
+
+impl<const SUB_CMD: u32> DeviceInfoCommand for PowerRailCommand<SUB_CMD> {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_LP;
+    const SUB_CMD: u32 = SUB_CMD;
+    type Payload = [u32; 2];
+}
+
+impl<const SUB_CMD: u32> QueryCommand for PowerRailCommand<SUB_CMD> {
+    type Output = RailReading;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(RailReading {
+            voltage: payload[0],
+            current: payload[1],
+        })
+    }
+}
+
+/// Query the rated power of the SoC
+#[derive(Debug)]
+pub struct RatedPowerCommand;
+
+impl DeviceInfoCommand for RatedPowerCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_LP;
+    const SUB_CMD: u32 = ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_GET_POWER_INFO;
+    type Payload = ffi::dcmi_lp_power_info;
+}
+
+impl QueryCommand for RatedPowerCommand {
+    type Output = u32;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload.soc_rated_power)
+    }
+}
+
+/// Query the reasons why the AI Core frequency is reduced
+#[derive(Debug)]
+pub struct ThrottleReasonsCommand;
+
+impl DeviceInfoCommand for ThrottleReasonsCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_LP;
+    const SUB_CMD: u32 = ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_AICORE_FREQREDUC_CAUSE;
+    type Payload = u32;
+}
+
+impl QueryCommand for ThrottleReasonsCommand {
+    type Output = ThrottleReasons;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(ThrottleReasons::from_bits_retain(payload))
+    }
+}
+
+/// Query all the performance profiles (TOPS working points) supported by the chip
+#[derive(Debug)]
+pub struct PerformanceProfilesCommand;
+
+impl DeviceInfoCommand for PerformanceProfilesCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_LP;
+    const SUB_CMD: u32 = ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_TOPS_DETAILS;
+    type Payload = ffi::dcmi_lp_tops_details_stru;
+}
+
+impl QueryCommand for PerformanceProfilesCommand {
+    type Output = Vec<PerformanceProfile>;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload
+            .each_work_tops
+            .into_iter()
+            .take(payload.tops_nums as usize)
+            .map(PerformanceProfile::from)
+            .collect())
+    }
+}
+
+/// Query the active performance profile
+#[derive(Debug)]
+pub struct GetPerformanceProfileCommand;
+
+impl DeviceInfoCommand for GetPerformanceProfileCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_LP;
+    const SUB_CMD: u32 = ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_GET_WORK_TOPS;
+    type Payload = ffi::dcmi_lp_cur_tops_stru;
+}
+
+impl QueryCommand for GetPerformanceProfileCommand {
+    type Output = u32;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload.work_tops)
+    }
+}
+
+/// Select the active performance profile
+#[derive(Debug)]
+pub struct SetPerformanceProfileCommand;
+
+impl DeviceInfoCommand for SetPerformanceProfileCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_LP;
+    const SUB_CMD: u32 = ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_SET_WORK_TOPS;
+    type Payload = ffi::dcmi_lp_work_tops_stru;
+}
+
+impl ConfigureCommand for SetPerformanceProfileCommand {
+    /// (profile ID, whether to persist the profile in flash)
+    type Input = (u32, bool);
+
+    fn encode(input: &Self::Input) -> DCMIResult<Self::Payload> {
+        Ok(ffi::dcmi_lp_work_tops_stru {
+            work_tops: input.0,
+            is_in_flash: input.1 as u32,
+            reserve: [0; ffi::DCMI_LP_WORK_TOPS_RESERVE as usize],
+        })
+    }
+}
+
+/// Query whether the adaptive clock gating (ACG) is enabled
+#[derive(Debug)]
+pub struct AcgCommand;
+
+impl DeviceInfoCommand for AcgCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_LP;
+    const SUB_CMD: u32 = ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_ACG;
+    type Payload = u32;
+}
+
+impl QueryCommand for AcgCommand {
+    type Output = bool;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload != 0)
+    }
+}
+
+/// Query the raw low power status
+#[derive(Debug)]
+pub struct LpStatusCommand;
+
+impl DeviceInfoCommand for LpStatusCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_LP;
+    const SUB_CMD: u32 = ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_STATUS;
+    type Payload = u32;
+}
+
+impl QueryCommand for LpStatusCommand {
+    type Output = u32;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload)
+    }
+}
+
+/// Enable or disable the idle power saving
+#[derive(Debug)]
+pub struct IdleSwitchCommand;
+
+impl DeviceInfoCommand for IdleSwitchCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_LP;
+    const SUB_CMD: u32 = ffi::DCMI_LP_SUB_CMD_DCMI_LP_SUB_CMD_SET_IDLE_SWITCH;
+    type Payload = u32;
+}
+
+impl ConfigureCommand for IdleSwitchCommand {
+    type Input = bool;
+
+    fn encode(input: &Self::Input) -> DCMIResult<Self::Payload> {
+        Ok(*input as u32)
+    }
+}
+
+impl Chip<'_, '_> {
+    /// Query the voltage and current of each power domain
+    ///
+    /// # Returns
+    /// raw voltage and current of each domain, `None` for the domains the chip does not support,
+    /// see [PowerRailCommand] for the assumed layout
+    pub fn get_power_rails(&self) -> DCMIResult<PowerRails> {
+        Ok(PowerRails {
+            aicore: optional(self.query::<AiCoreRailCommand>())?,
+            hybrid: optional(self.query::<HybridRailCommand>())?,
+            taishan: optional(self.query::<TaishanRailCommand>())?,
+            ddr: optional(self.query::<DdrRailCommand>())?,
+        })
+    }
+
+    /// Query the rated power of the SoC
+    ///
+    /// # Returns
+    /// rated power, unit: W
+    pub fn get_rated_power(&self) -> DCMIResult<u32> {
+        self.query::<RatedPowerCommand>()
+    }
+
+    /// Query the reasons why the AI Core frequency is reduced
+    ///
+    /// # Returns
+    /// throttle reasons, the raw cause bits reported by the driver
+    pub fn get_throttle_reasons(&self) -> DCMIResult<ThrottleReasons> {
+        self.query::<ThrottleReasonsCommand>()
+    }
+
+    /// Query the AI Core frequency together with the reasons it is reduced
+    ///
+    /// # Returns
+    /// current frequency, maximum frequency and throttle reasons
+    pub fn get_aicore_frequency(&self) -> DCMIResult<AiCoreFrequency> {
+        Ok(AiCoreFrequency {
+            current: self.get_frequency(FrequencyType::AICoreCurrent)?,
+            max: self.get_frequency(FrequencyType::AICoreMax)?,
+            reasons: self.get_throttle_reasons()?,
+        })
+    }
+
+    /// Query all the performance profiles (TOPS working points) supported by the chip
+    ///
+    /// # Returns
+    /// performance profile list, at most 10 entries
+    pub fn list_performance_profiles(&self) -> DCMIResult<Vec<PerformanceProfile>> {
+        self.query::<PerformanceProfilesCommand>()
+    }
+
+    /// Query the active performance profile
+    ///
+    /// # Returns
+    /// ID ([PerformanceProfile::work_tops]) of the active profile
+    pub fn get_performance_profile(&self) -> DCMIResult<u32> {
+        self.query::<GetPerformanceProfileCommand>()
+    }
+
+    /// Select the active performance profile
+    ///
+    /// # Parameters
+    /// - id: ID ([PerformanceProfile::work_tops]) of the profile
+    ///
+    /// # Errors
+    /// [DCMIError::InvalidParameter] if the chip does not support the profile, nothing will be written in this case
+    ///
+    /// # Notes
+    /// The profile is not persisted and falls back to the default after the chip is reset
+    pub fn set_performance_profile(&self, id: u32) -> DCMIResult<()> {
+        if !self
+            .list_performance_profiles()?
+            .iter()
+            .any(|profile| profile.work_tops == id)
+        {
+            return Err(DCMIError::InvalidParameter);
+        }
+
+        self.configure::<SetPerformanceProfileCommand>(&(id, false))
+    }
+
+    /// Query whether the adaptive clock gating (ACG) is enabled
+    ///
+    /// # Returns
+    /// `true` if enabled
+    pub fn get_acg_enable(&self) -> DCMIResult<bool> {
+        self.query::<AcgCommand>()
+    }
+
+    /// Query the raw low power status
+    ///
+    /// # Returns
+    /// low power status, the meaning depends on the product
+    pub fn get_lp_status(&self) -> DCMIResult<u32> {
+        self.query::<LpStatusCommand>()
+    }
+
+    /// Enable or disable the idle power saving
+    ///
+    /// # Parameters
+    /// - enable: `true` to let the chip lower its clocks when idle
+    pub fn set_idle_switch(&self, enable: bool) -> DCMIResult<()> {
+        self.configure::<IdleSwitchCommand>(&enable)
+    }
+}
@@ -0,0 +1,94 @@
+//! On-board memory subsystem of the chip
+
+use crate::device::chip::Chip;
+use crate::device::command::{ConfigureCommand, DeviceInfoCommand, QueryCommand};
+use crate::enums::{MemoryType, MemoryVendor};
+use crate::error::{optional, DCMIResult};
+use crate::structs::MemoryTopology;
+use hw_dcmi_wrapper_sys::bindings as ffi;
+
+/// Query a raw value of the memory subsystem, `SUB_CMD` is one of the `DCMI_SUB_CMD_MEMORY_*` sub commands
+#[derive(Debug)]
+pub struct MemoryInfoCommand<const SUB_CMD: u32>;
+
+/// Query the memory type
+pub type MemoryTypeCommand = MemoryInfoCommand<{ ffi::DCMI_SUB_CMD_MEMORY_TYPE }>;
+/// Query the memory capacity, unit: MB
+pub type MemoryCapacityCommand = MemoryInfoCommand<{ ffi::DCMI_SUB_CMD_MEMORY_CAPACITY }>;
+/// Query the number of memory channels
+pub type MemoryChannelCommand = MemoryInfoCommand<{ ffi::DCMI_SUB_CMD_MEMORY_CHANNEL }>;
+/// Query the number of ranks
+pub type MemoryRankNumCommand = MemoryInfoCommand<{ ffi::DCMI_SUB_CMD_MEMORY_RANK_NUM }>;
+/// Query whether ECC is enabled
+pub type MemoryEccEnableCommand = MemoryInfoCommand<{ ffi::DCMI_SUB_CMD_MEMORY_ECC_ENABLE }>;
+/// Query the memory manufacturer ID
+pub type MemoryManufacturesCommand = MemoryInfoCommand<{ ffi::DCMI_SUB_CMD_MEMORY_MANUFACTURES }>;
+/// Query the mata read once switch
+pub type GetMataReadOnceCommand =
+    MemoryInfoCommand<{ ffi::DCMI_SUB_CMD_MEMORY_GET_MATA_READ_ONCE }>;
+
+impl<const SUB_CMD: u32> DeviceInfoCommand for MemoryInfoCommand<SUB_CMD> {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_MEMORY;
+    const SUB_CMD: u32 = SUB_CMD;
+    type Payload = u32;
+}
+
+impl<const SUB_CMD: u32> QueryCommand for MemoryInfoCommand<SUB_CMD> {
+    type Output = u32;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload)
+    }
+}
+
+/// Set the mata read once switch
+#[derive(Debug)]
+pub struct SetMataReadOnceCommand;
+
+impl DeviceInfoCommand for SetMataReadOnceCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_MEMORY;
+    const SUB_CMD: u32 = ffi::DCMI_SUB_CMD_MEMORY_SET_MATA_READ_ONCE;
+    type Payload = u32;
+}
+
+impl ConfigureCommand for SetMataReadOnceCommand {
+    type Input = bool;
+
+    fn encode(input: &Self::Input) -> DCMIResult<Self::Payload> {
+        Ok(*input as u32)
+    }
+}
+
+impl Chip<'_, '_> {
+    /// Query the on-board memory topology
+    ///
+    /// # Returns
+    /// memory type, capacity, channels, ranks, ECC state and vendor,
+    /// an item is `None` if the chip does not support querying it
+    pub fn get_memory_topology(&self) -> DCMIResult<MemoryTopology> {
+        Ok(MemoryTopology {
+            memory_type: optional(self.query::<MemoryTypeCommand>())?.map(MemoryType::from),
+            capacity: optional(self.query::<MemoryCapacityCommand>())?,
+            channels: optional(self.query::<MemoryChannelCommand>())?,
+            ranks: optional(self.query::<MemoryRankNumCommand>())?,
+            ecc_enabled: optional(self.query::<MemoryEccEnableCommand>())?.map(|ecc| ecc != 0),
+            vendor: optional(self.query::<MemoryManufacturesCommand>())?.map(MemoryVendor::from),
+        })
+    }
+
+    /// Query whether the mata read once is enabled
+    ///
+    /// # Returns
+    /// `true` if enabled
+    pub fn get_mata_read_once(&self) -> DCMIResult<bool> {
+        Ok(self.query::<GetMataReadOnceCommand>()? != 0)
+    }
+
+    /// Enable or disable the mata read once
+    ///
+    /// # Parameters
+    /// - enable: `true` to enable, `false` to disable
+    pub fn set_mata_read_once(&self, enable: bool) -> DCMIResult<()> {
+        self.configure::<SetMataReadOnceCommand>(&enable)
+    }
+}
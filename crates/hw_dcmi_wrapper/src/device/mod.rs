@@ -2,4 +2,14 @@
 
 pub mod card;
 pub mod chip;
+pub mod command;
+pub mod hccs;
+pub mod lp;
+pub mod memory;
+pub mod recovery;
+pub mod sio;
+pub mod sm_cipher;
+pub mod ts;
+pub mod unit;
+pub mod user_config;
 pub mod vchip;
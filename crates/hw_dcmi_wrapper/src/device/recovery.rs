@@ -0,0 +1,126 @@
+//! Firmware recovery of the chip
+
+use crate::device::chip::Chip;
+use crate::device::command::{ConfigureCommand, DeviceInfoCommand, QueryCommand};
+use crate::enums::{RecoveryFlag, RecoveryStatus};
+use crate::error::DCMIResult;
+use hw_dcmi_wrapper_sys::bindings as ffi;
+
+/// Recovery command without meaningful input, `SUB_CMD` is one of the `DCMI_RCVR_SUB_CMD_*` sub commands
+#[derive(Debug)]
+pub struct RecoveryActionCommand<const SUB_CMD: u32>;
+
+/// Set the recovery flag
+pub type SetRecoveryFlagCommand =
+    RecoveryActionCommand<{ ffi::DCMI_RECOVERY_SUB_CMD_DCMI_RCVR_SUB_CMD_SET_FLAG }>;
+/// Clean the recovery flag
+pub type CleanRecoveryFlagCommand =
+    RecoveryActionCommand<{ ffi::DCMI_RECOVERY_SUB_CMD_DCMI_RCVR_SUB_CMD_CLEAN_FLAG }>;
+/// Reset the boot counter
+pub type ResetBootCountCommand =
+    RecoveryActionCommand<{ ffi::DCMI_RECOVERY_SUB_CMD_DCMI_RCVR_SUB_CMD_RESET_BOOT_CNT }>;
+
+impl<const SUB_CMD: u32> DeviceInfoCommand for RecoveryActionCommand<SUB_CMD> {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_RECOVERY;
+    const SUB_CMD: u32 = SUB_CMD;
+    type Payload = u32;
+}
+
+impl<const SUB_CMD: u32> ConfigureCommand for RecoveryActionCommand<SUB_CMD> {
+    type Input = ();
+
+    fn encode(_: &Self::Input) -> DCMIResult<Self::Payload> {
+        Ok(0)
+    }
+}
+
+/// Query the recovery flag
+#[derive(Debug)]
+pub struct GetRecoveryFlagCommand;
+
+impl DeviceInfoCommand for GetRecoveryFlagCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_RECOVERY;
+    const SUB_CMD: u32 = ffi::DCMI_RECOVERY_SUB_CMD_DCMI_RCVR_SUB_CMD_GET_FLAG;
+    type Payload = u32;
+}
+
+impl QueryCommand for GetRecoveryFlagCommand {
+    type Output = RecoveryFlag;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload.into())
+    }
+}
+
+/// Report the recovery status
+#[derive(Debug)]
+pub struct SetRecoveryStatusCommand;
+
+impl DeviceInfoCommand for SetRecoveryStatusCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_RECOVERY;
+    const SUB_CMD: u32 = ffi::DCMI_RECOVERY_SUB_CMD_DCMI_RCVR_SUB_CMD_SET_STATUS;
+    type Payload = u32;
+}
+
+impl ConfigureCommand for SetRecoveryStatusCommand {
+    type Input = RecoveryStatus;
+
+    fn encode(input: &Self::Input) -> DCMIResult<Self::Payload> {
+        Ok((*input).into())
+    }
+}
+
+/// Firmware recovery control of the chip
+///
+/// Use [Chip::recovery] to create it
+#[derive(Debug)]
+pub struct Recovery<'a, 'b, 'c>
+where
+    'b: 'a,
+    'c: 'b,
+{
+    pub(crate) chip: &'a Chip<'b, 'c>,
+}
+
+impl<'b, 'c> Recovery<'_, 'b, 'c>
+where
+    'c: 'b,
+{
+    /// Query the chip of this recovery control
+    pub fn chip(&self) -> &Chip<'b, 'c> {
+        self.chip
+    }
+}
+
+impl Recovery<'_, '_, '_> {
+    /// Query the recovery flag
+    ///
+    /// # Returns
+    /// whether the device boots into recovery mode
+    pub fn get_flag(&self) -> DCMIResult<RecoveryFlag> {
+        self.chip.query::<GetRecoveryFlagCommand>()
+    }
+
+    /// Set the recovery flag, the device will boot into recovery mode
+    pub fn set_flag(&self) -> DCMIResult<()> {
+        self.chip.configure::<SetRecoveryFlagCommand>(&())
+    }
+
+    /// Clean the recovery flag, the device will boot normally
+    pub fn clean_flag(&self) -> DCMIResult<()> {
+        self.chip.configure::<CleanRecoveryFlagCommand>(&())
+    }
+
+    /// Reset the boot counter, which decides when a boot-looping device falls into recovery mode
+    pub fn reset_boot_count(&self) -> DCMIResult<()> {
+        self.chip.configure::<ResetBootCountCommand>(&())
+    }
+
+    /// Report the recovery status to the device
+    ///
+    /// # Parameters
+    /// - status: result of the recovery
+    pub fn set_status(&self, status: RecoveryStatus) -> DCMIResult<()> {
+        self.chip.configure::<SetRecoveryStatusCommand>(&status)
+    }
+}
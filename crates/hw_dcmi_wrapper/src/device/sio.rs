@@ -0,0 +1,51 @@
+//! Die-to-die SIO link of the chip
+
+use crate::device::chip::Chip;
+use crate::device::command::{DeviceInfoCommand, QueryCommand, RawPayload};
+use crate::error::{optional, DCMIResult};
+use crate::structs::{ErrorCounters, SioCrcErrors};
+use hw_dcmi_wrapper_sys::bindings as ffi;
+
+unsafe impl RawPayload for ffi::dcmi_sio_crc_err_statistics_info {}
+
+/// Query the SIO CRC error counters
+#[derive(Debug)]
+pub struct SioCrcErrorsCommand;
+
+impl DeviceInfoCommand for SioCrcErrorsCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_SIO;
+    const SUB_CMD: u32 = ffi::DCMI_SIO_SUB_CMD_DCMI_SIO_SUB_CMD_CRC_ERR_STATISTICS;
+    type Payload = ffi::dcmi_sio_crc_err_statistics_info;
+}
+
+impl QueryCommand for SioCrcErrorsCommand {
+    type Output = SioCrcErrors;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload.into())
+    }
+}
+
+impl Chip<'_, '_> {
+    /// Query the CRC error counters of the die-to-die SIO link
+    ///
+    /// # Returns
+    /// tx/rx CRC error count
+    ///
+    /// # Notes
+    /// Only dual-die chips have the SIO link
+    pub fn get_sio_crc_errors(&self) -> DCMIResult<SioCrcErrors> {
+        self.query::<SioCrcErrorsCommand>()
+    }
+
+    /// Query the error counters of the chip links
+    ///
+    /// # Returns
+    /// PCIE error rate and SIO CRC error counters, the SIO part is `None` if the chip does not support it
+    pub fn get_error_counters(&self) -> DCMIResult<ErrorCounters> {
+        Ok(ErrorCounters {
+            pcie: self.get_pcie_error_cnt()?,
+            sio_crc: optional(self.get_sio_crc_errors())?,
+        })
+    }
+}
@@ -0,0 +1,245 @@
+//! SM4 cipher offloaded to the crypto engine of the chip
+//!
+//! Only SM4-CBC encryption and decryption are wrapped.
+//! SM2 is not covered as the DCMI defines no key type for it, and the SM3 digest
+//! (`SM_KEY_TYPE_SM3_NORMAL_SUMMARY`) is not covered as the DCMI does not document
+//! which of `dcmi_sm_encrypt` and `dcmi_sm_decrypt` computes it nor the size of its output
+
+use crate::device::chip::Chip;
+use crate::error::{DCMIError, DCMIResult, GetDataError};
+use hw_dcmi_wrapper_sys::bindings as ffi;
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/// SM4 block size, unit: byte
+pub const SM4_BLOCK_SIZE: usize = 16;
+
+/// SM4 key size, unit: byte
+pub const SM4_KEY_SIZE: usize = 16;
+
+/// SM4-CBC initialization vector size, unit: byte
+pub const SM4_IV_SIZE: usize = SM4_BLOCK_SIZE;
+
+/// Overwrite the buffer with zero in a way that will not be optimized out
+fn zeroize(buf: &mut [u8]) {
+    for byte in buf.iter_mut() {
+        unsafe { std::ptr::write_volatile(byte, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// Output buffer of the SM functions, zeroized on drop so that no plain text is left behind on error
+struct SmOutput(Vec<u8>);
+
+impl Drop for SmOutput {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+/// SM4 key, zeroized on drop
+pub struct SmKey(Vec<u8>);
+
+impl SmKey {
+    /// Create a new SM4 key
+    ///
+    /// # Errors
+    /// [DCMIError::InvalidParameter] if the key is not [SM4_KEY_SIZE] bytes
+    pub fn new(key: &[u8]) -> DCMIResult<Self> {
+        if key.len() != SM4_KEY_SIZE {
+            return Err(DCMIError::InvalidParameter);
+        }
+        Ok(SmKey(key.to_vec()))
+    }
+
+    /// Query the length of the key, unit: byte
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the key is empty, always false for a valid key
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::fmt::Debug for SmKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SmKey({} bytes)", self.0.len())
+    }
+}
+
+impl Drop for SmKey {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+/// SM4-CBC initialization vector, zeroized on drop
+pub struct SmIv(Vec<u8>);
+
+impl SmIv {
+    /// Create a new SM4-CBC initialization vector
+    ///
+    /// # Errors
+    /// [DCMIError::InvalidParameter] if the IV is not [SM4_IV_SIZE] bytes
+    pub fn new(iv: &[u8]) -> DCMIResult<Self> {
+        if iv.len() != SM4_IV_SIZE {
+            return Err(DCMIError::InvalidParameter);
+        }
+        Ok(SmIv(iv.to_vec()))
+    }
+
+    /// Query the length of the IV, unit: byte
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the IV is empty, always false for a valid IV
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl std::fmt::Debug for SmIv {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "SmIv({} bytes)", self.0.len())
+    }
+}
+
+impl Drop for SmIv {
+    fn drop(&mut self) {
+        zeroize(&mut self.0);
+    }
+}
+
+/// SM4-CBC cipher running on the crypto engine of the chip
+///
+/// Use [Chip::sm_cipher] to create it, the key material is zeroized when the cipher is dropped
+#[derive(Debug)]
+pub struct SmCipher<'a, 'b, 'c>
+where
+    'b: 'a,
+    'c: 'b,
+{
+    pub(crate) chip: &'a Chip<'b, 'c>,
+    pub(crate) key: SmKey,
+    pub(crate) iv: SmIv,
+}
+
+/// Parameter of the DCMI SM functions, zeroized on drop
+struct SmParm(ffi::dcmi_sm_parm);
+
+impl SmParm {
+    fn new(key_type: ffi::SM_KEY_TYPE, key: &SmKey, iv: &SmIv) -> Self {
+        let mut parm: ffi::dcmi_sm_parm = unsafe { std::mem::zeroed() };
+        parm.key_type = key_type;
+        parm.key_len = key.0.len() as u32;
+        parm.key[..key.0.len()].copy_from_slice(&key.0);
+        parm.iv_len = iv.0.len() as u32;
+        parm.iv[..iv.0.len()].copy_from_slice(&iv.0);
+        SmParm(parm)
+    }
+}
+
+impl Drop for SmParm {
+    fn drop(&mut self) {
+        zeroize(&mut self.0.key);
+        zeroize(&mut self.0.iv);
+    }
+}
+
+impl<'a, 'b, 'c> SmCipher<'a, 'b, 'c>
+where
+    'b: 'a,
+    'c: 'b,
+{
+    /// Create a SM4-CBC cipher
+    ///
+    /// # Parameters
+    /// - chip: the chip whose crypto engine is used
+    /// - key: SM4 key
+    /// - iv: SM4 initialization vector
+    pub fn new(chip: &'a Chip<'b, 'c>, key: SmKey, iv: SmIv) -> Self {
+        SmCipher { chip, key, iv }
+    }
+
+    /// Query the chip of this cipher
+    pub fn chip(&self) -> &Chip<'b, 'c> {
+        self.chip
+    }
+}
+
+impl SmCipher<'_, '_, '_> {
+    /// Encrypt the data
+    ///
+    /// # Parameters
+    /// - data: plain text
+    ///
+    /// # Returns
+    /// cipher text, at most one block longer than the plain text
+    pub fn encrypt(&self, data: &[u8]) -> DCMIResult<Vec<u8>> {
+        let mut parm = SmParm::new(ffi::SM_KEY_TYPE_SM4_CBC_ENCRYPT, &self.key, &self.iv);
+        let mut out = SmOutput(vec![0u8; data.len() + SM4_BLOCK_SIZE]);
+        let mut out_len = out.0.len() as u32;
+        let mut sm_data = Self::sm_data(data, &mut out.0, &mut out_len)?;
+
+        call_dcmi_function!(
+            dcmi_sm_encrypt,
+            self.chip.card.dcmi.lib,
+            self.chip.card.id as i32,
+            self.chip.id as i32,
+            &mut parm.0,
+            &mut sm_data
+        );
+
+        Self::finish(out, out_len)
+    }
+
+    /// Decrypt the data
+    ///
+    /// # Parameters
+    /// - data: cipher text
+    ///
+    /// # Returns
+    /// plain text
+    pub fn decrypt(&self, data: &[u8]) -> DCMIResult<Vec<u8>> {
+        let mut parm = SmParm::new(ffi::SM_KEY_TYPE_SM4_CBC_DECRYPT, &self.key, &self.iv);
+        let mut out = SmOutput(vec![0u8; data.len().max(SM4_BLOCK_SIZE)]);
+        let mut out_len = out.0.len() as u32;
+        let mut sm_data = Self::sm_data(data, &mut out.0, &mut out_len)?;
+
+        call_dcmi_function!(
+            dcmi_sm_decrypt,
+            self.chip.card.dcmi.lib,
+            self.chip.card.id as i32,
+            self.chip.id as i32,
+            &mut parm.0,
+            &mut sm_data
+        );
+
+        Self::finish(out, out_len)
+    }
+
+    fn sm_data(data: &[u8], out: &mut [u8], out_len: &mut u32) -> DCMIResult<ffi::dcmi_sm_data> {
+        if data.is_empty() || out.len() > u32::MAX as usize {
+            return Err(DCMIError::InvalidParameter);
+        }
+        Ok(ffi::dcmi_sm_data {
+            in_buf: data.as_ptr(),
+            in_len: data.len() as u32,
+            out_buf: out.as_mut_ptr(),
+            out_len,
+        })
+    }
+
+    fn finish(mut out: SmOutput, out_len: u32) -> DCMIResult<Vec<u8>> {
+        let out_len = out_len as usize;
+        if out_len > out.0.len() {
+            return Err(GetDataError::InvalidData.into());
+        }
+        zeroize(&mut out.0[out_len..]);
+        let mut out = std::mem::take(&mut out.0);
+        out.truncate(out_len);
+        Ok(out)
+    }
+}
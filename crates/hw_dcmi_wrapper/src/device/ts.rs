@@ -0,0 +1,141 @@
+//! Task scheduler (TS) of the chip
+
+use crate::device::chip::Chip;
+use crate::device::command::{ConfigureCommand, DeviceInfoCommand, QueryCommand};
+use crate::enums::FftsType;
+use crate::error::{optional, DCMIResult};
+use crate::structs::{TsFaultMask, TsUtilization};
+use hw_dcmi_wrapper_sys::bindings as ffi;
+
+/// Query the AI Core utilization from the task scheduler
+#[derive(Debug)]
+pub struct TsAiCoreUtilizationCommand;
+
+impl DeviceInfoCommand for TsAiCoreUtilizationCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_TS;
+    const SUB_CMD: u32 = ffi::DCMI_TS_SUB_CMD_DCMI_TS_SUB_CMD_AICORE_UTILIZATION_RATE;
+    type Payload = u32;
+}
+
+impl QueryCommand for TsAiCoreUtilizationCommand {
+    type Output = u32;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload)
+    }
+}
+
+/// Query the Vector Core utilization from the task scheduler
+#[derive(Debug)]
+pub struct TsVectorCoreUtilizationCommand;
+
+impl DeviceInfoCommand for TsVectorCoreUtilizationCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_TS;
+    const SUB_CMD: u32 = ffi::DCMI_TS_SUB_CMD_DCMI_TS_SUB_CMD_VECTORCORE_UTILIZATION_RATE;
+    type Payload = u32;
+}
+
+impl QueryCommand for TsVectorCoreUtilizationCommand {
+    type Output = u32;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload)
+    }
+}
+
+/// Query the FFTS type
+#[derive(Debug)]
+pub struct FftsTypeCommand;
+
+impl DeviceInfoCommand for FftsTypeCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_TS;
+    const SUB_CMD: u32 = ffi::DCMI_TS_SUB_CMD_DCMI_TS_SUB_CMD_FFTS_TYPE;
+    type Payload = u32;
+}
+
+impl QueryCommand for FftsTypeCommand {
+    type Output = FftsType;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(payload.into())
+    }
+}
+
+/// Query the fault mask of the task scheduler
+#[derive(Debug)]
+pub struct GetTsFaultMaskCommand;
+
+impl DeviceInfoCommand for GetTsFaultMaskCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_TS;
+    const SUB_CMD: u32 = ffi::DCMI_TS_SUB_CMD_DCMI_TS_SUB_CMD_GET_FAULT_MASK;
+    type Payload = u32;
+}
+
+impl QueryCommand for GetTsFaultMaskCommand {
+    type Output = TsFaultMask;
+
+    fn decode(payload: Self::Payload) -> DCMIResult<Self::Output> {
+        Ok(TsFaultMask::from_bits_retain(payload))
+    }
+}
+
+/// Set the fault mask of the task scheduler
+#[derive(Debug)]
+pub struct SetTsFaultMaskCommand;
+
+impl DeviceInfoCommand for SetTsFaultMaskCommand {
+    const MAIN_CMD: u32 = ffi::dcmi_main_cmd_DCMI_MAIN_CMD_TS;
+    const SUB_CMD: u32 = ffi::DCMI_TS_SUB_CMD_DCMI_TS_SUB_CMD_SET_FAULT_MASK;
+    type Payload = u32;
+}
+
+impl ConfigureCommand for SetTsFaultMaskCommand {
+    type Input = TsFaultMask;
+
+    fn encode(input: &Self::Input) -> DCMIResult<Self::Payload> {
+        Ok(input.bits())
+    }
+}
+
+impl Chip<'_, '_> {
+    /// Query the utilization from the task scheduler
+    ///
+    /// # Returns
+    /// AI Core and Vector Core utilization, unit: %
+    ///
+    /// # Notes
+    /// Unlike [Chip::get_utilization_rate], the result is still available when profiling is on
+    pub fn get_ts_utilization(&self) -> DCMIResult<TsUtilization> {
+        Ok(TsUtilization {
+            aicore: self.query::<TsAiCoreUtilizationCommand>()?,
+            vector_core: optional(self.query::<TsVectorCoreUtilizationCommand>())?,
+        })
+    }
+
+    /// Query the FFTS type of the task scheduler
+    ///
+    /// # Returns
+    /// FFTS type
+    pub fn get_ffts_type(&self) -> DCMIResult<FftsType> {
+        self.query::<FftsTypeCommand>()
+    }
+
+    /// Query the fault mask of the task scheduler
+    ///
+    /// # Returns
+    /// fault mask, a set bit means the fault is masked
+    pub fn get_ts_fault_mask(&self) -> DCMIResult<TsFaultMask> {
+        self.query::<GetTsFaultMaskCommand>()
+    }
+
+    /// Set the fault mask of the task scheduler
+    ///
+    /// # Parameters
+    /// - mask: fault mask, a set bit means the fault is masked
+    ///
+    /// # Warning
+    /// Masked faults are no longer reported, restore the mask once burn-in is done
+    pub fn set_ts_fault_mask(&self, mask: TsFaultMask) -> DCMIResult<()> {
+        self.configure::<SetTsFaultMaskCommand>(&mask)
+    }
+}
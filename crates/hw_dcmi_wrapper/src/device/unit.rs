@@ -0,0 +1,41 @@
+//! Flat handle of a unit (NPU, MCU or CPU) on the host
+
+use crate::device::card::Card;
+use crate::device::chip::Chip;
+use crate::enums::UnitType;
+
+/// Unit on the host, owns its card so that units of different cards can be collected together
+///
+/// Returned by [crate::DCMI::npu_chips] and [crate::DCMI::all_units]
+#[derive(Debug, Clone)]
+pub struct DeviceUnit<'a> {
+    pub(crate) card: Card<'a>,
+    pub(crate) id: u32,
+    pub(crate) unit_type: UnitType,
+}
+
+impl<'a> DeviceUnit<'a> {
+    /// Query the card of this unit
+    pub fn card(&self) -> &Card<'a> {
+        &self.card
+    }
+
+    /// Query the chip ID of this unit in its card
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Query the type of this unit
+    pub fn unit_type(&self) -> &UnitType {
+        &self.unit_type
+    }
+
+    /// Create the chip of this unit
+    pub fn chip(&self) -> Chip<'_, 'a> {
+        Chip {
+            id: self.id,
+            card: &self.card,
+            unit_type: Some(self.unit_type.clone()),
+        }
+    }
+}
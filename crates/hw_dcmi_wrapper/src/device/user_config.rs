@@ -0,0 +1,165 @@
+//! Named user configuration of the chip
+
+use crate::device::chip::Chip;
+use crate::error::{DCMIError, DCMIResult, GetDataError};
+use hw_dcmi_wrapper_sys::bindings as ffi;
+use std::ffi::CString;
+
+/// Named user configuration item of the chip
+///
+/// Use [Chip::user_config] to create it
+#[derive(Debug)]
+pub struct UserConfig<'a, 'b, 'c>
+where
+    'b: 'a,
+    'c: 'b,
+{
+    pub(crate) name: CString,
+    pub(crate) chip: &'a Chip<'b, 'c>,
+}
+
+impl<'a, 'b, 'c> UserConfig<'a, 'b, 'c>
+where
+    'b: 'a,
+    'c: 'b,
+{
+    /// Create a handle of the named user configuration item
+    ///
+    /// # Errors
+    /// [DCMIError::InvalidParameter] if the name is empty or contains a nul byte
+    pub fn new(chip: &'a Chip<'b, 'c>, name: &str) -> DCMIResult<Self> {
+        if name.is_empty() {
+            return Err(DCMIError::InvalidParameter);
+        }
+        let name = CString::new(name).map_err(|_| DCMIError::InvalidParameter)?;
+        Ok(UserConfig { name, chip })
+    }
+
+    /// Query the name of this configuration item
+    pub fn name(&self) -> &str {
+        self.name.to_str().unwrap()
+    }
+
+    /// Query the chip of this configuration item
+    pub fn chip(&self) -> &Chip<'b, 'c> {
+        self.chip
+    }
+}
+
+impl UserConfig<'_, '_, '_> {
+    /// Query the raw value of this configuration item
+    ///
+    /// # Parameters
+    /// - size: size of the value, unit: byte
+    ///
+    /// # Returns
+    /// raw value
+    ///
+    /// # Errors
+    /// [DCMIError::ConfigInfoNotExist] if the item has never been set
+    ///
+    /// # Notes
+    /// DCMI library does not export `dcmi_get_device_user_config`, `dcmi_get_user_config` is used instead
+    pub fn get(&self, size: usize) -> DCMIResult<Vec<u8>> {
+        if size == 0 || size > u32::MAX as usize {
+            return Err(DCMIError::InvalidParameter);
+        }
+        let mut buf = vec![0u8; size];
+
+        call_dcmi_function!(
+            dcmi_get_user_config,
+            self.chip.card.dcmi.lib,
+            self.chip.card.id as i32,
+            self.chip.id as i32,
+            self.name.as_ptr(),
+            buf.len() as u32,
+            buf.as_mut_ptr()
+        );
+
+        Ok(buf)
+    }
+
+    /// Set the raw value of this configuration item, the value persists across reboots
+    ///
+    /// # Parameters
+    /// - value: raw value
+    pub fn set(&self, value: &[u8]) -> DCMIResult<()> {
+        if value.is_empty() || value.len() > u32::MAX as usize {
+            return Err(DCMIError::InvalidParameter);
+        }
+        let mut buf = value.to_vec();
+
+        call_dcmi_function!(
+            dcmi_set_user_config,
+            self.chip.card.dcmi.lib,
+            self.chip.card.id as i32,
+            self.chip.id as i32,
+            self.name.as_ptr(),
+            buf.len() as u32,
+            buf.as_mut_ptr()
+        );
+
+        Ok(())
+    }
+
+    /// Clear this configuration item, the default value will be used after that
+    pub fn clear(&self) -> DCMIResult<()> {
+        call_dcmi_function!(
+            dcmi_clear_device_user_config,
+            self.chip.card.dcmi.lib,
+            self.chip.card.id as i32,
+            self.chip.id as i32,
+            self.name.as_ptr()
+        );
+
+        Ok(())
+    }
+}
+
+/// Typed user configuration item
+///
+/// Implement this trait to access a configuration item not provided by this crate with
+/// [Chip::get_user_config_item] and [Chip::set_user_config_item]
+pub trait UserConfigItem: Sized {
+    /// Name of the configuration item
+    const NAME: &'static str;
+    /// Size of the encoded value, unit: byte
+    const SIZE: usize;
+
+    /// Encode the value into raw bytes, the length must be [UserConfigItem::SIZE]
+    fn encode(&self) -> DCMIResult<Vec<u8>>;
+
+    /// Decode the value from raw bytes
+    fn decode(raw: &[u8]) -> DCMIResult<Self>;
+}
+
+/// Certificate expiration alarm threshold, unit: day
+///
+/// The valid range is `7..=180`, the default value is 90
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CertExpiredThreshold(pub u8);
+
+impl UserConfigItem for CertExpiredThreshold {
+    const NAME: &'static str = "cert_expired_threshold";
+    const SIZE: usize = 1;
+
+    fn encode(&self) -> DCMIResult<Vec<u8>> {
+        if !(ffi::CERT_PERIOD_MIN..=ffi::CERT_PERIOD_MAX).contains(&(self.0 as u32)) {
+            return Err(DCMIError::InvalidParameter);
+        }
+        Ok(vec![self.0])
+    }
+
+    fn decode(raw: &[u8]) -> DCMIResult<Self> {
+        match raw {
+            [days] => Ok(CertExpiredThreshold(*days)),
+            _ => Err(GetDataError::InvalidData.into()),
+        }
+    }
+}
+
+impl Default for CertExpiredThreshold {
+    fn default() -> Self {
+        CertExpiredThreshold(ffi::DEFAULT_EXPIERD_THRESHOLD as u8)
+    }
+}
@@ -298,3 +298,383 @@ impl From<VChipCreateParam> for ffi::dcmi_create_vdev_res_stru {
         }
     }
 }
+
+/// NVE level, the log level of the device firmware
+///
+/// # Notes
+/// The DCMI headers define no constants for the level, the named levels are the values 0 to 4 as assumed here.
+/// Any other value is kept as [NveLevel::Unknown], so that it is written back unchanged
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NveLevel {
+    /// Debug, the most verbose level, value 0
+    Debug,
+    /// Info, value 1
+    Info,
+    /// Warning, value 2
+    Warning,
+    /// Error, value 3
+    Error,
+    /// Null, log is disabled, value 4
+    Null,
+    /// Unknown level, the raw value
+    Unknown(i32),
+}
+
+impl From<i32> for NveLevel {
+    fn from(level: i32) -> Self {
+        match level {
+            0 => NveLevel::Debug,
+            1 => NveLevel::Info,
+            2 => NveLevel::Warning,
+            3 => NveLevel::Error,
+            4 => NveLevel::Null,
+            level => NveLevel::Unknown(level),
+        }
+    }
+}
+
+impl From<NveLevel> for i32 {
+    fn from(level: NveLevel) -> Self {
+        match level {
+            NveLevel::Debug => 0,
+            NveLevel::Info => 1,
+            NveLevel::Warning => 2,
+            NveLevel::Error => 3,
+            NveLevel::Null => 4,
+            NveLevel::Unknown(level) => level,
+        }
+    }
+}
+
+/// Power state of the chip
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PowerState {
+    /// Suspend, low power state
+    Suspend,
+    /// Power off
+    PowerOff,
+    /// Reset
+    Reset,
+    /// Built-in self test
+    Bist,
+}
+
+impl From<PowerState> for ffi::DCMI_POWER_STATE {
+    fn from(state: PowerState) -> Self {
+        match state {
+            PowerState::Suspend => ffi::DCMI_POWER_STATE_DCMI_POWER_STATE_SUSPEND,
+            PowerState::PowerOff => ffi::DCMI_POWER_STATE_DCMI_POWER_STATE_POWEROFF,
+            PowerState::Reset => ffi::DCMI_POWER_STATE_DCMI_POWER_STATE_RESET,
+            PowerState::Bist => ffi::DCMI_POWER_STATE_DCMI_POWER_STATE_BIST,
+        }
+    }
+}
+
+/// Resume mode from the low power state
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ResumeMode {
+    /// Resume by the power button
+    Button,
+    /// Resume after a period of time
+    Time,
+}
+
+impl From<ResumeMode> for ffi::DCMI_LP_RESUME_MODE {
+    fn from(mode: ResumeMode) -> Self {
+        match mode {
+            ResumeMode::Button => ffi::DCMI_LP_RESUME_MODE_DCMI_POWER_RESUME_MODE_BUTTON,
+            ResumeMode::Time => ffi::DCMI_LP_RESUME_MODE_DCMI_POWER_RESUME_MODE_TIME,
+        }
+    }
+}
+
+/// Secure revocation file type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RevocationType {
+    /// SOC revocation file
+    Soc,
+    /// CMS certificate revocation list
+    CmsCrl,
+    /// CMS certificate revocation list extension
+    CmsCrlExt,
+}
+
+impl From<RevocationType> for ffi::dcmi_revo_type {
+    fn from(revo: RevocationType) -> Self {
+        match revo {
+            RevocationType::Soc => ffi::dcmi_revo_type_DCMI_REVOCATION_TYPE_SOC,
+            RevocationType::CmsCrl => ffi::dcmi_revo_type_DCMI_REVOCATION_TYPE_CMS_CRL,
+            RevocationType::CmsCrlExt => ffi::dcmi_revo_type_DCMI_REVOCATION_TYPE_CMS_CRL_EXT,
+        }
+    }
+}
+
+/// Out-of-band channel state, whether the BMC can reach the device through the out-of-band path
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OutbandChannelState {
+    /// Out-of-band channel is abnormal
+    Abnormal,
+    /// Out-of-band channel is normal
+    Normal,
+    /// Unknown state, the raw value
+    Unknown(i32),
+}
+
+impl From<i32> for OutbandChannelState {
+    fn from(state: i32) -> Self {
+        match state {
+            0 => OutbandChannelState::Abnormal,
+            1 => OutbandChannelState::Normal,
+            state => OutbandChannelState::Unknown(state),
+        }
+    }
+}
+
+/// Compatibility between the device firmware and the driver
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Compatibility {
+    /// Firmware and driver are compatible
+    Ok,
+    /// Firmware and driver are not compatible
+    NotOk,
+    /// Compatibility is unknown, also used for any value not mentioned in the reference manual
+    Unknown,
+}
+
+impl From<ffi::dcmi_device_compat> for Compatibility {
+    fn from(compat: ffi::dcmi_device_compat) -> Self {
+        match compat {
+            ffi::dcmi_device_compat_DCMI_COMPAT_OK => Compatibility::Ok,
+            ffi::dcmi_device_compat_DCMI_COMPAT_NOK => Compatibility::NotOk,
+            _ => Compatibility::Unknown,
+        }
+    }
+}
+
+/// Product type
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ProductType {
+    /// Atlas 300I inference card
+    Atlas300I,
+    /// Atlas 300I Pro inference card
+    Atlas300IPro,
+    /// Atlas 300I Duo inference card
+    Atlas300IDuo,
+    /// Atlas 300V video analysis card
+    Atlas300V,
+    /// Atlas 300V Pro video analysis card
+    Atlas300VPro,
+    /// Atlas 300T training card
+    Atlas300T,
+    /// Atlas 800 server
+    Atlas800,
+    /// Atlas 800I A2 inference server
+    Atlas800IA2,
+    /// Atlas 800T A2 training server
+    Atlas800TA2,
+    /// Atlas 900 cluster
+    Atlas900,
+    /// Atlas 900 A2 PoD cluster
+    Atlas900A2PoD,
+    /// Other product, the raw product type string
+    Other(String),
+}
+
+impl From<&str> for ProductType {
+    fn from(product_type: &str) -> Self {
+        match product_type.trim() {
+            "Atlas 300I" => ProductType::Atlas300I,
+            "Atlas 300I Pro" => ProductType::Atlas300IPro,
+            "Atlas 300I Duo" => ProductType::Atlas300IDuo,
+            "Atlas 300V" => ProductType::Atlas300V,
+            "Atlas 300V Pro" => ProductType::Atlas300VPro,
+            "Atlas 300T" => ProductType::Atlas300T,
+            "Atlas 800" => ProductType::Atlas800,
+            "Atlas 800I A2" => ProductType::Atlas800IA2,
+            "Atlas 800T A2" => ProductType::Atlas800TA2,
+            "Atlas 900" => ProductType::Atlas900,
+            "Atlas 900 A2 PoD" => ProductType::Atlas900A2PoD,
+            s => ProductType::Other(s.into()),
+        }
+    }
+}
+
+/// HBM vendor, decoded from the JEP106 manufacturer code
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum HbmVendor {
+    /// Samsung
+    Samsung,
+    /// SK hynix
+    SkHynix,
+    /// Micron
+    Micron,
+    /// Unknown vendor, the raw manufacturer ID
+    Unknown(u16),
+}
+
+impl From<u16> for HbmVendor {
+    /// `manufacturer_id` of `dcmi_hbm_product_info` is taken as a JEDEC JEP106 manufacturer code of bank 1,
+    /// including the parity bit, any other value is [HbmVendor::Unknown]
+    fn from(manufacturer_id: u16) -> Self {
+        match manufacturer_id {
+            0xce => HbmVendor::Samsung,
+            0xad => HbmVendor::SkHynix,
+            0x2c => HbmVendor::Micron,
+            id => HbmVendor::Unknown(id),
+        }
+    }
+}
+
+/// Computing power type
+///
+/// # Notes
+/// The DCMI headers define no constants for the type of `dcmi_get_computing_power_info`,
+/// only type 0 is named here, use [ComputePowerType::Other] to pass any other raw type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ComputePowerType {
+    /// AI Core computing power, type 0
+    AiCore,
+    /// Other type, the raw value
+    Other(i32),
+}
+
+impl From<ComputePowerType> for i32 {
+    fn from(power_type: ComputePowerType) -> Self {
+        match power_type {
+            ComputePowerType::AiCore => 0,
+            ComputePowerType::Other(power_type) => power_type,
+        }
+    }
+}
+
+/// FFTS (fast fine-grained task scheduling) type of the task scheduler
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum FftsType {
+    /// FFTS
+    Ffts,
+    /// FFTS+
+    FftsPlus,
+    /// Unknown type, the raw value
+    Unknown(u32),
+}
+
+impl From<u32> for FftsType {
+    fn from(ffts_type: u32) -> Self {
+        match ffts_type {
+            0 => FftsType::Ffts,
+            1 => FftsType::FftsPlus,
+            ffts_type => FftsType::Unknown(ffts_type),
+        }
+    }
+}
+
+/// Firmware recovery flag, whether the device boots into recovery mode
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RecoveryFlag {
+    /// Flag is clear, the device boots normally
+    Clear,
+    /// Flag is set, the device boots into recovery mode
+    Set,
+}
+
+impl From<u32> for RecoveryFlag {
+    fn from(flag: u32) -> Self {
+        match flag {
+            0 => RecoveryFlag::Clear,
+            _ => RecoveryFlag::Set,
+        }
+    }
+}
+
+/// Firmware recovery status reported to the device
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum RecoveryStatus {
+    /// Recovery succeeded
+    Success,
+    /// Recovery failed
+    Failure,
+}
+
+impl From<RecoveryStatus> for u32 {
+    fn from(status: RecoveryStatus) -> Self {
+        match status {
+            RecoveryStatus::Success => 0,
+            RecoveryStatus::Failure => 1,
+        }
+    }
+}
+
+/// On-board memory type, decoded from the JEDEC SPD DRAM device type
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MemoryType {
+    /// DDR3 SDRAM
+    Ddr3,
+    /// DDR4 SDRAM
+    Ddr4,
+    /// DDR5 SDRAM
+    Ddr5,
+    /// LPDDR4 SDRAM
+    Lpddr4,
+    /// LPDDR4X SDRAM
+    Lpddr4x,
+    /// LPDDR5 SDRAM
+    Lpddr5,
+    /// LPDDR5X SDRAM
+    Lpddr5x,
+    /// Unknown type, the raw value
+    Unknown(u32),
+}
+
+impl From<u32> for MemoryType {
+    fn from(memory_type: u32) -> Self {
+        match memory_type {
+            0x0b => MemoryType::Ddr3,
+            0x0c => MemoryType::Ddr4,
+            0x12 => MemoryType::Ddr5,
+            0x10 => MemoryType::Lpddr4,
+            0x11 => MemoryType::Lpddr4x,
+            0x13 => MemoryType::Lpddr5,
+            0x15 => MemoryType::Lpddr5x,
+            memory_type => MemoryType::Unknown(memory_type),
+        }
+    }
+}
+
+/// On-board memory vendor, decoded from the JEP106 manufacturer code reported by the JEDEC SPD
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MemoryVendor {
+    /// Samsung
+    Samsung,
+    /// SK hynix
+    SkHynix,
+    /// Micron
+    Micron,
+    /// Unknown vendor, the raw manufacturer ID
+    Unknown(u32),
+}
+
+impl From<u32> for MemoryVendor {
+    /// JEP106 manufacturer codes of bank 1, including the parity bit
+    fn from(manufacturer_id: u32) -> Self {
+        match manufacturer_id {
+            0xce => MemoryVendor::Samsung,
+            0xad => MemoryVendor::SkHynix,
+            0x2c => MemoryVendor::Micron,
+            id => MemoryVendor::Unknown(id),
+        }
+    }
+}
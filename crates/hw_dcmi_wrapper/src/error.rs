@@ -1,10 +1,13 @@
 //! Error types for DCMI functions.
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 use std::ffi::c_int;
 use thiserror::Error;
 
 /// Error type for DCMI function which gets data.
-#[derive(Error, Debug)]
+#[derive(Error, Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum GetDataError {
     /// Invalid data
     #[error("Invalid data")]
@@ -14,6 +17,34 @@ pub enum GetDataError {
     ReadError,
 }
 
+/// Error type for the rejection of secure revocation file.
+#[derive(Error, Debug)]
+pub enum RevocationError {
+    /// File format or content is invalid
+    #[error("Invalid revocation file")]
+    InvalidFile,
+    /// Signature verification of the file failed
+    #[error("Revocation file verification failed")]
+    VerificationFailed,
+    /// Installing the file is not permitted on the device
+    #[error("Revocation not permitted")]
+    NotPermitted,
+    /// Device failed to store the file
+    #[error("Revocation file write failed")]
+    WriteFailed,
+}
+
+/// Error type for the rejection of MCU license.
+#[derive(Error, Debug)]
+pub enum LicenseError {
+    /// License format or content is invalid
+    #[error("Invalid license")]
+    InvalidLicense,
+    /// Installing the license is not permitted on the MCU
+    #[error("License operation not permitted")]
+    NotPermitted,
+}
+
 /// Error type for DCMI functions.
 #[derive(Error, Debug)]
 pub enum DCMIError {
@@ -25,6 +56,18 @@ pub enum DCMIError {
     #[error(transparent)]
     GetDataError(#[from] GetDataError),
 
+    /// Error when reading file
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+
+    /// Secure revocation file rejected by the device
+    #[error(transparent)]
+    RevocationError(#[from] RevocationError),
+
+    /// License rejected by the MCU
+    #[error(transparent)]
+    LicenseError(#[from] LicenseError),
+
     /// Invalid parameter
     #[error("Invalid parameter")]
     InvalidParameter,
@@ -77,6 +120,10 @@ pub enum DCMIError {
     #[error("Not support in container")]
     NotSupportInContainer,
 
+    /// File operate fail
+    #[error("File operate fail")]
+    FileOperateFail,
+
     /// Reset fail
     #[error("Reset fail")]
     ResetFail,
@@ -97,6 +144,10 @@ pub enum DCMIError {
     #[error("Device id / function not support")]
     NotSupport,
 
+    /// Configuration information not exist
+    #[error("Configuration information not exist")]
+    ConfigInfoNotExist,
+
     /// Unknown error
     #[error("Unknown error, error code: {0}")]
     UnknownError(i32),
@@ -124,11 +175,22 @@ pub fn dcmi_try(code: c_int) -> Result<(), DCMIError> {
         ffi::DCMI_ERR_CODE_RECV_MSG_FAIL => Err(DCMIError::ReceiveMessageFail),
         ffi::DCMI_ERR_CODE_NOT_REDAY => Err(DCMIError::NotReady),
         ffi::DCMI_ERR_CODE_NOT_SUPPORT_IN_CONTAINER => Err(DCMIError::NotSupportInContainer),
+        ffi::DCMI_ERR_CODE_FILE_OPERATE_FAIL => Err(DCMIError::FileOperateFail),
         ffi::DCMI_ERR_CODE_RESET_FAIL => Err(DCMIError::ResetFail),
         ffi::DCMI_ERR_CODE_ABORT_OPERATE => Err(DCMIError::AbortOperation),
         ffi::DCMI_ERR_CODE_IS_UPGRADING => Err(DCMIError::IsUpgrading),
         ffi::DCMI_ERR_CODE_RESOURCE_OCCUPIED => Err(DCMIError::ResourceOccupied),
+        ffi::DCMI_ERR_CODE_CONFIG_INFO_NOT_EXIST => Err(DCMIError::ConfigInfoNotExist),
         ffi::DCMI_ERR_CODE_NOT_SUPPORT => Err(DCMIError::NotSupport),
         _ => Err(DCMIError::UnknownError(code)),
     }
 }
+
+/// Turns [DCMIError::NotSupport] into `None`, for the parts of a report the chip may not support.
+pub(crate) fn optional<T>(result: DCMIResult<T>) -> DCMIResult<Option<T>> {
+    match result {
+        Ok(value) => Ok(Some(value)),
+        Err(DCMIError::NotSupport) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
@@ -30,8 +30,10 @@
 //! - `load_dynamic`: enable dynamic loading of the dcmi shared library
 #![warn(missing_docs)]
 
-use crate::enums::HealthState;
-use crate::error::{dcmi_try, DCMIResult};
+use crate::device::card::Card;
+use crate::device::unit::DeviceUnit;
+use crate::enums::{HealthState, UnitType};
+use crate::error::{dcmi_try, DCMIResult, GetDataError};
 use std::cell::Cell;
 
 use hw_dcmi_wrapper_sys::bindings as ffi;
@@ -256,6 +258,71 @@ impl DCMI {
             .collect())
     }
 
+    /// Query the number of all devices on the host
+    ///
+    /// # Returns
+    /// number of devices
+    ///
+    /// # Errors
+    /// [GetDataError::InvalidData] if the driver reports a negative count
+    pub fn device_count(&self) -> DCMIResult<u32> {
+        let mut all_device_count = 0i32;
+
+        call_dcmi_function!(dcmi_get_all_device_count, self.lib, &mut all_device_count);
+
+        Ok(u32::try_from(all_device_count).map_err(|_| GetDataError::InvalidData)?)
+    }
+
+    /// Query every NPU chip on the host
+    ///
+    /// # Returns
+    /// NPU chip list, in logical ID order
+    pub fn npu_chips(&self) -> DCMIResult<Vec<DeviceUnit<'_>>> {
+        let mut npu_chips = Vec::new();
+        for unit in self.all_units()? {
+            if unit.unit_type != UnitType::NPU {
+                continue;
+            }
+            let mut logic_id = 0i32;
+
+            call_dcmi_function!(
+                dcmi_get_device_logic_id,
+                self.lib,
+                &mut logic_id,
+                unit.card.id as i32,
+                unit.id as i32
+            );
+
+            npu_chips.push((logic_id, unit));
+        }
+        npu_chips.sort_by_key(|(logic_id, _)| *logic_id);
+
+        Ok(npu_chips.into_iter().map(|(_, unit)| unit).collect())
+    }
+
+    /// Query every unit on the host, including MCU and CPU units
+    ///
+    /// # Returns
+    /// unit list, grouped by card, the NPU chips of each card come first, followed by its MCU and CPU
+    pub fn all_units(&self) -> DCMIResult<Vec<DeviceUnit<'_>>> {
+        let mut units = Vec::new();
+        for card in Card::query_cards(self)? {
+            let (npu_chips, mcu_chip, cpu_chip) = card.get_chips()?;
+            let typed_ids = npu_chips
+                .into_iter()
+                .map(|chip| (chip.id, UnitType::NPU))
+                .chain(mcu_chip.map(|chip| (chip.id, UnitType::MCU)))
+                .chain(cpu_chip.map(|chip| (chip.id, UnitType::CPU)));
+            units.extend(typed_ids.map(|(id, unit_type)| DeviceUnit {
+                card: card.clone(),
+                id,
+                unit_type,
+            }));
+        }
+
+        Ok(units)
+    }
+
     /// Set the container service enable
     ///
     /// Initialize all devices in the current container for subsequent conversion between logical and physical IDs
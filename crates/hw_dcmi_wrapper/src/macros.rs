@@ -1,6 +1,6 @@
 //! some useful macros
 
-macro_rules! call_dcmi_function {
+macro_rules! try_call_dcmi_function {
     ($func_name:ident, $dcmi:expr $(, $arg:expr)*) => {
         crate::dcmi_try(
             unsafe {
@@ -13,7 +13,13 @@ macro_rules! call_dcmi_function {
                     crate::ffi::$func_name($($arg),*)
                 }
             }
-        )?
+        )
+    };
+}
+
+macro_rules! call_dcmi_function {
+    ($func_name:ident, $dcmi:expr $(, $arg:expr)*) => {
+        try_call_dcmi_function!($func_name, $dcmi $(, $arg)*)?
     };
 }
 
@@ -26,3 +32,53 @@ macro_rules! check_value {
         }
     };
 }
+
+macro_rules! flags_type {
+    (
+        $(#[$outer:meta])*
+        pub struct $name:ident($bits:ty) {
+            $(
+                $(#[$inner:meta])*
+                const $flag:ident = $value:expr;
+            )*
+        }
+    ) => {
+        $(#[$outer])*
+        pub struct $name($bits);
+
+        impl $name {
+            $(
+                $(#[$inner])*
+                pub const $flag: $name = $name($value);
+            )*
+
+            /// Create from the raw bits, unknown bits are retained
+            pub const fn from_bits_retain(bits: $bits) -> Self {
+                $name(bits)
+            }
+
+            /// Raw bits
+            pub const fn bits(&self) -> $bits {
+                self.0
+            }
+
+            /// Whether no flag is set
+            pub const fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            /// Whether all the flags in `other` are set
+            pub const fn contains(&self, other: $name) -> bool {
+                self.0 & other.0 == other.0
+            }
+        }
+
+        impl std::ops::BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, rhs: $name) -> $name {
+                $name(self.0 | rhs.0)
+            }
+        }
+    };
+}
@@ -1,8 +1,11 @@
 //! Wrapped structs for the DCMI peripheral
 
+use crate::enums::{MemoryType, MemoryVendor};
+use crate::error::{DCMIError, DCMIResult, GetDataError};
 use hw_dcmi_wrapper_sys::bindings as ffi;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::ffi::CStr;
 
 /// Chip information
@@ -845,3 +848,500 @@ impl From<ffi::dcmi_llc_perf> for LLCPerf {
         }
     }
 }
+
+/// Versioned key/value map stored in the card customized information area
+///
+/// # Notes
+/// The encoded layout is `magic(2) | version(1) | count(1) | (key_len(1) | key | value_len(1) | value)*`,
+/// keys and values are UTF-8 strings of at most 255 bytes each
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CustomizedKV {
+    /// Key/value entries
+    pub entries: BTreeMap<String, String>,
+}
+
+impl CustomizedKV {
+    /// Magic bytes prefixing the encoded map
+    pub const MAGIC: [u8; 2] = *b"KV";
+    /// Version of the encoded layout
+    pub const VERSION: u8 = 1;
+    /// Well-known key of the asset tag
+    pub const ASSET_TAG: &'static str = "asset_tag";
+    /// Well-known key of the rack position
+    pub const RACK_POSITION: &'static str = "rack_position";
+    /// Well-known key of the owner
+    pub const OWNER: &'static str = "owner";
+
+    /// Query the value of the key
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.get(key).map(String::as_str)
+    }
+
+    /// Insert a key/value entry, return the old value if the key already exists
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Into<String>) -> Option<String> {
+        self.entries.insert(key.into(), value.into())
+    }
+
+    /// Remove a key/value entry, return the removed value
+    pub fn remove(&mut self, key: &str) -> Option<String> {
+        self.entries.remove(key)
+    }
+
+    /// Encode the map into raw bytes
+    ///
+    /// # Parameters
+    /// - max_len: maximum length of the encoded bytes
+    ///
+    /// # Errors
+    /// [DCMIError::InvalidParameter] if any key or value is longer than 255 bytes,
+    /// there are more than 255 entries, or the encoded bytes exceed `max_len`
+    pub fn encode(&self, max_len: usize) -> DCMIResult<Vec<u8>> {
+        if self.entries.len() > u8::MAX as usize {
+            return Err(DCMIError::InvalidParameter);
+        }
+
+        let mut data = Vec::with_capacity(max_len);
+        data.extend_from_slice(&Self::MAGIC);
+        data.push(Self::VERSION);
+        data.push(self.entries.len() as u8);
+        for (key, value) in &self.entries {
+            for field in [key.as_bytes(), value.as_bytes()] {
+                if field.len() > u8::MAX as usize {
+                    return Err(DCMIError::InvalidParameter);
+                }
+                data.push(field.len() as u8);
+                data.extend_from_slice(field);
+            }
+        }
+
+        if data.len() > max_len {
+            return Err(DCMIError::InvalidParameter);
+        }
+        Ok(data)
+    }
+
+    /// Decode the map from raw bytes
+    ///
+    /// # Returns
+    /// decoded map, `None` if the bytes are empty or not written by [CustomizedKV::encode]
+    ///
+    /// # Notes
+    /// Trailing bytes after the last entry are ignored
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        let (header, mut rest) = data.split_at_checked(4)?;
+        if header[..2] != Self::MAGIC || header[2] != Self::VERSION {
+            return None;
+        }
+
+        let mut next_field = || -> Option<String> {
+            let (&len, tail) = rest.split_first()?;
+            let (field, tail) = tail.split_at_checked(len as usize)?;
+            rest = tail;
+            String::from_utf8(field.to_vec()).ok()
+        };
+
+        let mut entries = BTreeMap::new();
+        for _ in 0..header[3] {
+            let key = next_field()?;
+            let value = next_field()?;
+            entries.insert(key, value);
+        }
+
+        Some(CustomizedKV { entries })
+    }
+}
+
+/// Media engine (DVPP) utilization, unit: 1%
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MediaEngineUtilization {
+    /// Video decoder (VDEC) utilization
+    pub vdec: u32,
+    /// Vision pre-processing core (VPC) utilization
+    pub vpc: u32,
+    /// Video encoder (VENC) utilization
+    pub venc: u32,
+    /// JPEG encoder (JPEGE) utilization
+    pub jpege: u32,
+    /// JPEG decoder (JPEGD) utilization
+    pub jpegd: u32,
+}
+
+impl TryFrom<ffi::dcmi_dvpp_ratio> for MediaEngineUtilization {
+    type Error = GetDataError;
+
+    /// Fails with [GetDataError::InvalidData] if the driver reports a negative ratio
+    fn try_from(dvpp_ratio: ffi::dcmi_dvpp_ratio) -> Result<Self, Self::Error> {
+        let ratio = |ratio: i32| u32::try_from(ratio).map_err(|_| GetDataError::InvalidData);
+        Ok(MediaEngineUtilization {
+            vdec: ratio(dvpp_ratio.vdec_ratio)?,
+            vpc: ratio(dvpp_ratio.vpc_ratio)?,
+            venc: ratio(dvpp_ratio.venc_ratio)?,
+            jpege: ratio(dvpp_ratio.jpege_ratio)?,
+            jpegd: ratio(dvpp_ratio.jpegd_ratio)?,
+        })
+    }
+}
+
+/// Fan reading
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FanReading {
+    /// Fan ID, starts from 1
+    pub fan_id: u32,
+    /// Fan speed, unit: RPM, or the reason why the sensor could not be read
+    pub speed: Result<u32, GetDataError>,
+}
+
+/// Snapshot of the device switches, `None` if the switch is not supported by the product
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DeviceSwitches {
+    /// Whether the device sharing is enabled
+    pub share: Option<bool>,
+    /// Whether the SSH access to the device is enabled
+    pub ssh: Option<bool>,
+    /// Whether the P2P communication of the device is enabled
+    pub device_p2p: Option<bool>,
+    /// Whether the P2P communication is enabled in the driver
+    pub p2p: Option<bool>,
+}
+
+/// Split of the on-device Arm cores between control CPU, AI CPU and data CPU roles
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CpuNumConfig {
+    /// Number of control CPU cores
+    pub ctrl: u8,
+    /// Number of AI CPU cores
+    pub aicpu: u8,
+    /// Number of data CPU cores
+    pub data: u8,
+}
+
+impl CpuNumConfig {
+    /// Length of the raw configuration buffer, unit: byte
+    pub(crate) const BUF_LEN: usize = 16;
+
+    /// Total number of cores in this configuration
+    pub fn total(&self) -> u32 {
+        self.ctrl as u32 + self.aicpu as u32 + self.data as u32
+    }
+
+    /// Decode the raw configuration buffer
+    ///
+    /// # Notes
+    /// The buffer holds one byte per role in the order control CPU, data CPU, AI CPU, the remaining bytes are reserved.
+    /// This follows the `buf` description of `dcmi_get_device_cpu_num_config` and `dcmi_set_device_cpu_num_config`
+    /// in the DCMI API reference, which is also the `ctrl:data:aicpu` order taken by `npu-smi set -t cpu-num-cfg`
+    pub(crate) fn decode(buf: &[u8; Self::BUF_LEN]) -> Self {
+        CpuNumConfig {
+            ctrl: buf[0],
+            data: buf[1],
+            aicpu: buf[2],
+        }
+    }
+
+    /// Encode into the raw configuration buffer, see [CpuNumConfig::decode] for the layout
+    pub(crate) fn encode(&self) -> [u8; Self::BUF_LEN] {
+        let mut buf = [0u8; Self::BUF_LEN];
+        buf[0] = self.ctrl;
+        buf[1] = self.data;
+        buf[2] = self.aicpu;
+        buf
+    }
+}
+
+/// HCCS status
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HccsStatus {
+    /// PCS status, 0 means all PCS are normal
+    pub pcs_status: u32,
+}
+
+impl HccsStatus {
+    /// Whether all PCS are normal
+    pub fn is_healthy(&self) -> bool {
+        self.pcs_status == 0
+    }
+}
+
+impl From<ffi::dcmi_hccs_statues> for HccsStatus {
+    fn from(hccs_status: ffi::dcmi_hccs_statues) -> Self {
+        HccsStatus {
+            pcs_status: hccs_status.pcs_status,
+        }
+    }
+}
+
+/// Lane state of a HCCS port
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HccsPortLanes {
+    /// Port (PCS) ID
+    pub port_id: u32,
+    /// Whether the port is up
+    pub up: bool,
+    /// Bitmap of the lanes which are up, bit `n` stands for lane `n`
+    pub lane_bitmap: u32,
+}
+
+impl HccsPortLanes {
+    /// Whether the specified lane is up
+    pub fn lane_up(&self, lane: u32) -> bool {
+        lane < u32::BITS && self.lane_bitmap & (1 << lane) != 0
+    }
+
+    /// Number of the lanes which are up
+    pub fn up_lane_count(&self) -> u32 {
+        self.lane_bitmap.count_ones()
+    }
+}
+
+/// Lane map of all HCCS ports
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HccsLanes {
+    /// Lane state of each port, indexed by port ID
+    pub ports: Vec<HccsPortLanes>,
+}
+
+impl HccsLanes {
+    /// Ports which are up
+    pub fn up_ports(&self) -> impl Iterator<Item = &HccsPortLanes> {
+        self.ports.iter().filter(|port| port.up)
+    }
+}
+
+impl From<ffi::dcmi_hccs_lane_info> for HccsLanes {
+    fn from(lane_info: ffi::dcmi_hccs_lane_info) -> Self {
+        HccsLanes {
+            ports: lane_info
+                .pcs_lane_bitmap
+                .into_iter()
+                .enumerate()
+                .map(|(port_id, lane_bitmap)| HccsPortLanes {
+                    port_id: port_id as u32,
+                    up: lane_info.hccs_port_pcs_bitmap & (1 << port_id) != 0,
+                    lane_bitmap,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Traffic counters of a HCCS port
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HccsPortStatistics {
+    /// Port (PCS) ID
+    pub port_id: u32,
+    /// Transmitted packet count
+    pub tx_count: u32,
+    /// Received packet count
+    pub rx_count: u32,
+    /// CRC error count
+    pub crc_error_count: u32,
+}
+
+/// Traffic counters of all HCCS ports
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct HccsStatistics {
+    /// Counters of each port, indexed by port ID
+    pub ports: Vec<HccsPortStatistics>,
+}
+
+impl HccsStatistics {
+    /// Difference between these counters and the earlier ones
+    ///
+    /// # Parameters
+    /// - previous: counters queried earlier from the same chip
+    ///
+    /// # Returns
+    /// counters increased since `previous`, wrap-around of the counters is taken into account
+    pub fn delta(&self, previous: &HccsStatistics) -> HccsStatistics {
+        HccsStatistics {
+            ports: self
+                .ports
+                .iter()
+                .zip(&previous.ports)
+                .map(|(current, previous)| HccsPortStatistics {
+                    port_id: current.port_id,
+                    tx_count: current.tx_count.wrapping_sub(previous.tx_count),
+                    rx_count: current.rx_count.wrapping_sub(previous.rx_count),
+                    crc_error_count: current
+                        .crc_error_count
+                        .wrapping_sub(previous.crc_error_count),
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<ffi::dcmi_hccs_statistic_info> for HccsStatistics {
+    fn from(statistic_info: ffi::dcmi_hccs_statistic_info) -> Self {
+        HccsStatistics {
+            ports: (0..ffi::DCMI_HCCS_MAX_PCS_NUM as usize)
+                .map(|port_id| HccsPortStatistics {
+                    port_id: port_id as u32,
+                    tx_count: statistic_info.tx_cnt[port_id],
+                    rx_count: statistic_info.rx_cnt[port_id],
+                    crc_error_count: statistic_info.crc_err_cnt[port_id],
+                })
+                .collect(),
+        }
+    }
+}
+
+/// CRC error counters of the die-to-die SIO link
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SioCrcErrors {
+    /// Transmit CRC error count
+    pub tx_error_count: u16,
+    /// Receive CRC error count
+    pub rx_error_count: u16,
+}
+
+impl From<ffi::dcmi_sio_crc_err_statistics_info> for SioCrcErrors {
+    fn from(statistics_info: ffi::dcmi_sio_crc_err_statistics_info) -> Self {
+        SioCrcErrors {
+            tx_error_count: statistics_info.tx_error_count,
+            rx_error_count: statistics_info.rx_error_count,
+        }
+    }
+}
+
+/// Error counters of the chip links
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ErrorCounters {
+    /// PCIE error rate
+    pub pcie: ChipPCIEErrorRate,
+    /// SIO CRC error counters, `None` if the chip has no SIO link
+    pub sio_crc: Option<SioCrcErrors>,
+}
+
+/// Voltage and current of a power rail, as raw values reported by the driver
+///
+/// # Notes
+/// The DCMI defines no structure or unit for the `*_VOLTAGE_CURRENT` sub commands,
+/// so the values are not scaled or converted here
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RailReading {
+    /// Raw voltage value, the first 32-bit word of the reply
+    pub voltage: u32,
+    /// Raw current value, the second 32-bit word of the reply
+    pub current: u32,
+}
+
+/// Voltage and current of each power domain, `None` if the domain is not supported by the chip
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PowerRails {
+    /// AI Core domain
+    pub aicore: Option<RailReading>,
+    /// Hybrid domain
+    pub hybrid: Option<RailReading>,
+    /// TaiShan (CPU) domain
+    pub taishan: Option<RailReading>,
+    /// DDR domain
+    pub ddr: Option<RailReading>,
+}
+
+flags_type! {
+    /// Reasons why the AI Core frequency is reduced, a set of flags
+    ///
+    /// # Notes
+    /// The DCMI does not define the meaning of the individual bits, so no bit is named here,
+    /// all bits are kept as they are reported by the driver
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct ThrottleReasons(u32) {}
+}
+
+/// AI Core frequency together with the reasons it is reduced
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AiCoreFrequency {
+    /// Current frequency, unit: MHz
+    pub current: u32,
+    /// Maximum frequency under TDP, unit: MHz
+    pub max: u32,
+    /// Reasons why the frequency is reduced
+    pub reasons: ThrottleReasons,
+}
+
+/// Performance profile (TOPS working point) of the chip
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct PerformanceProfile {
+    /// Profile ID, the working TOPS
+    pub work_tops: u32,
+    /// AI Core computing power, unit: TOPS
+    pub aicore_tops: u32,
+    /// AI Core frequency, unit: MHz
+    pub aicore_frequency: u32,
+    /// AI Core voltage, unit: mV
+    pub aicore_voltage: u32,
+    /// CPU frequency, unit: MHz
+    pub cpu_frequency: u32,
+    /// CPU voltage, unit: mV
+    pub cpu_voltage: u32,
+}
+
+impl From<ffi::dcmi_lp_each_tops_details> for PerformanceProfile {
+    fn from(tops_details: ffi::dcmi_lp_each_tops_details) -> Self {
+        PerformanceProfile {
+            work_tops: tops_details.work_tops,
+            aicore_tops: tops_details.aic_tops,
+            aicore_frequency: tops_details.aic_freq,
+            aicore_voltage: tops_details.aic_vol,
+            cpu_frequency: tops_details.cpu_freq,
+            cpu_voltage: tops_details.cpu_vol,
+        }
+    }
+}
+
+/// Utilization reported by the task scheduler, unit: %
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TsUtilization {
+    /// AI Core utilization
+    pub aicore: u32,
+    /// Vector Core utilization, `None` if the chip has no Vector Core
+    pub vector_core: Option<u32>,
+}
+
+flags_type! {
+    /// Fault mask of the task scheduler, a set of fault bits, a set bit means the fault is masked
+    ///
+    /// # Notes
+    /// The DCMI does not define the meaning of the individual bits, so no bit is named here.
+    /// Build the mask from raw bits with [TsFaultMask::from_bits_retain], all bits are kept as they are read and written
+    #[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct TsFaultMask(u32) {}
+}
+
+/// On-board memory topology
+#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MemoryTopology {
+    /// Memory type, `None` if not supported
+    pub memory_type: Option<MemoryType>,
+    /// Memory capacity, unit: MB, `None` if not supported
+    pub capacity: Option<u32>,
+    /// Number of memory channels, `None` if not supported
+    pub channels: Option<u32>,
+    /// Number of ranks, `None` if not supported
+    pub ranks: Option<u32>,
+    /// Whether ECC is enabled, `None` if not supported
+    pub ecc_enabled: Option<bool>,
+    /// Memory vendor, `None` if not supported
+    pub vendor: Option<MemoryVendor>,
+}
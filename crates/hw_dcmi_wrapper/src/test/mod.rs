@@ -1,6 +1,16 @@
 use crate::device::card::Card;
+use crate::device::command::QueryCommand;
+use crate::device::lp::ThrottleReasonsCommand;
+use crate::device::sm_cipher::{SmIv, SmKey};
+use crate::device::user_config::{CertExpiredThreshold, UserConfigItem};
 use crate::device::vchip::VChip;
-use crate::enums::VChipCreateParam;
+use crate::enums::{
+    Compatibility, HbmVendor, MemoryType, MemoryVendor, NveLevel, OutbandChannelState, ProductType,
+    VChipCreateParam,
+};
+use crate::structs::{
+    CpuNumConfig, CustomizedKV, HccsLanes, HccsStatistics, ThrottleReasons, TsFaultMask,
+};
 use crate::DCMI;
 use std::ops::Not;
 use std::sync::{LazyLock, Mutex};
@@ -14,6 +24,18 @@ fn test_get_card_list() {
     println!("card num: {}, card list: {:?}", card_list.len(), card_list);
 }
 
+#[test]
+#[ignore]
+fn test_npu_chips() {
+    let dcmi = &*DCMI_INSTANCE.lock().unwrap();
+    let device_count = dcmi.device_count().unwrap();
+    let npu_chips = dcmi.npu_chips().unwrap();
+    println!("device count: {}, npu chips: {:?}", device_count, npu_chips);
+    for unit in dcmi.all_units().unwrap() {
+        println!("unit type: {:?}, chip: {:?}", unit.unit_type(), unit.chip());
+    }
+}
+
 #[test]
 #[ignore]
 fn test_get_memory_info() {
@@ -107,3 +129,248 @@ fn test_chip_mod() {
     let new_mode = VChip::get_recovery_mode(dcmi).unwrap();
     assert_eq!(anti_mode, new_mode);
 }
+
+#[test]
+#[ignore]
+fn test_get_license() {
+    let dcmi = &*DCMI_INSTANCE.lock().unwrap();
+    let card_list = Card::query_cards(dcmi).unwrap();
+    for card in card_list {
+        let license = card.get_license().unwrap();
+        println!("card {} license: {} bytes", card.id(), license.len());
+    }
+}
+
+#[test]
+fn test_customized_kv_round_trip() {
+    let mut kv = CustomizedKV::default();
+    kv.insert(CustomizedKV::ASSET_TAG, "NPU-000123");
+    kv.insert(CustomizedKV::RACK_POSITION, "R12-U07");
+    kv.insert(CustomizedKV::OWNER, "inference");
+    let mut data = kv.encode(1024).unwrap();
+    data.resize(1024, 0);
+    assert_eq!(CustomizedKV::decode(&data), Some(kv));
+}
+
+#[test]
+fn test_customized_kv_foreign_data() {
+    assert_eq!(CustomizedKV::decode(&[]), None);
+    assert_eq!(CustomizedKV::decode(&[0u8; 1024]), None);
+    assert_eq!(CustomizedKV::decode(b"hello world"), None);
+    assert_eq!(CustomizedKV::decode(b"KV\x01\x02\x03key"), None);
+}
+
+#[test]
+fn test_customized_kv_overflow() {
+    let mut kv = CustomizedKV::default();
+    kv.insert(CustomizedKV::OWNER, "x".repeat(200));
+    assert!(kv.encode(64).is_err());
+    kv.insert(CustomizedKV::ASSET_TAG, "x".repeat(256));
+    assert!(kv.encode(1024).is_err());
+}
+
+#[test]
+#[ignore]
+fn test_user_config() {
+    let dcmi = &*DCMI_INSTANCE.lock().unwrap();
+    let card_list = Card::query_cards(dcmi).unwrap();
+    let card = card_list.first().unwrap();
+    let (chips, _mcu_chip, _cpu_chip) = card.get_chips().unwrap();
+    let chip = chips.first().unwrap();
+    chip.set_user_config_item(&CertExpiredThreshold(30))
+        .unwrap();
+    let threshold: CertExpiredThreshold = chip.get_user_config_item().unwrap();
+    assert_eq!(threshold, CertExpiredThreshold(30));
+    chip.user_config(CertExpiredThreshold::NAME)
+        .unwrap()
+        .clear()
+        .unwrap();
+}
+
+#[test]
+#[ignore]
+fn test_sm4_round_trip() {
+    let dcmi = &*DCMI_INSTANCE.lock().unwrap();
+    let card_list = Card::query_cards(dcmi).unwrap();
+    let card = card_list.first().unwrap();
+    let (chips, _mcu_chip, _cpu_chip) = card.get_chips().unwrap();
+    let chip = chips.first().unwrap();
+    let cipher = chip.sm_cipher(
+        SmKey::new(&[0x11; 16]).unwrap(),
+        SmIv::new(&[0x22; 16]).unwrap(),
+    );
+    let plain = b"0123456789abcdef".to_vec();
+    let encrypted = cipher.encrypt(&plain).unwrap();
+    assert_eq!(cipher.decrypt(&encrypted).unwrap(), plain);
+}
+
+#[test]
+fn test_sm_key_length() {
+    assert!(SmKey::new(&[]).is_err());
+    assert!(SmKey::new(&[0; 15]).is_err());
+    assert!(SmKey::new(&[0; 512]).is_err());
+    assert!(SmIv::new(&[0; 17]).is_err());
+    assert!(SmIv::new(&[0; 64]).is_err());
+    assert_eq!(SmKey::new(&[0; 16]).unwrap().len(), 16);
+    assert_eq!(SmIv::new(&[0; 16]).unwrap().len(), 16);
+}
+
+#[test]
+fn test_product_type_parse() {
+    assert_eq!(
+        ProductType::from("Atlas 300I Duo"),
+        ProductType::Atlas300IDuo
+    );
+    assert_eq!(
+        ProductType::from("Atlas 300V Pro\n"),
+        ProductType::Atlas300VPro
+    );
+    assert_eq!(ProductType::from("Atlas 800"), ProductType::Atlas800);
+    assert_eq!(ProductType::from("Atlas 800I A2"), ProductType::Atlas800IA2);
+    assert_eq!(ProductType::from("Atlas 800T A2"), ProductType::Atlas800TA2);
+    assert_eq!(
+        ProductType::from("Atlas 800 (Model 9000)"),
+        ProductType::Other("Atlas 800 (Model 9000)".into())
+    );
+    assert_eq!(
+        ProductType::from("Atlas 200I DK A2"),
+        ProductType::Other("Atlas 200I DK A2".into())
+    );
+}
+
+#[test]
+fn test_hbm_vendor_from_manufacturer_id() {
+    assert_eq!(HbmVendor::from(0xce), HbmVendor::Samsung);
+    assert_eq!(HbmVendor::from(0xad), HbmVendor::SkHynix);
+    assert_eq!(HbmVendor::from(0x2c), HbmVendor::Micron);
+    assert_eq!(HbmVendor::from(0x01), HbmVendor::Unknown(0x01));
+    assert_eq!(HbmVendor::from(0xff), HbmVendor::Unknown(0xff));
+    assert_eq!(HbmVendor::from(0x1234), HbmVendor::Unknown(0x1234));
+}
+
+#[test]
+fn test_hccs_lanes_decode() {
+    let mut lane_info: hw_dcmi_wrapper_sys::bindings::dcmi_hccs_lane_info =
+        unsafe { std::mem::zeroed() };
+    lane_info.hccs_port_pcs_bitmap = 0b101;
+    lane_info.pcs_lane_bitmap[0] = 0b1111;
+    lane_info.pcs_lane_bitmap[2] = 0b0111;
+
+    let lanes = HccsLanes::from(lane_info);
+    let up_ports = lanes
+        .up_ports()
+        .map(|port| port.port_id)
+        .collect::<Vec<_>>();
+    assert_eq!(up_ports, vec![0, 2]);
+    assert_eq!(lanes.ports[0].up_lane_count(), 4);
+    assert!(lanes.ports[2].lane_up(2));
+    assert!(lanes.ports[2].lane_up(3).not());
+    assert!(lanes.ports[2].lane_up(40).not());
+}
+
+#[test]
+fn test_hccs_statistics_delta() {
+    let mut statistic_info: hw_dcmi_wrapper_sys::bindings::dcmi_hccs_statistic_info =
+        unsafe { std::mem::zeroed() };
+    statistic_info.tx_cnt[1] = u32::MAX;
+    statistic_info.crc_err_cnt[1] = 3;
+    let previous = HccsStatistics::from(statistic_info);
+
+    statistic_info.tx_cnt[1] = 9;
+    statistic_info.crc_err_cnt[1] = 5;
+    let current = HccsStatistics::from(statistic_info);
+
+    let delta = current.delta(&previous);
+    assert_eq!(delta.ports[1].tx_count, 10);
+    assert_eq!(delta.ports[1].crc_error_count, 2);
+    assert_eq!(delta.ports[0].rx_count, 0);
+}
+
+#[test]
+fn test_throttle_reasons() {
+    let reasons = ThrottleReasonsCommand::decode(0x8000_000b).unwrap();
+    assert_eq!(reasons.bits(), 0x8000_000b);
+    assert!(reasons.contains(ThrottleReasons::from_bits_retain(0b1010)));
+    assert!(reasons
+        .contains(ThrottleReasons::from_bits_retain(0b0100))
+        .not());
+    assert!(ThrottleReasonsCommand::decode(0).unwrap().is_empty());
+}
+
+#[test]
+fn test_memory_type_and_vendor() {
+    assert_eq!(MemoryType::from(0x0c), MemoryType::Ddr4);
+    assert_eq!(MemoryType::from(0x11), MemoryType::Lpddr4x);
+    assert_eq!(MemoryType::from(0xff), MemoryType::Unknown(0xff));
+    assert_eq!(MemoryVendor::from(0xce), MemoryVendor::Samsung);
+    assert_eq!(MemoryVendor::from(0x2c), MemoryVendor::Micron);
+    assert_eq!(MemoryVendor::from(0x01), MemoryVendor::Unknown(0x01));
+    assert_eq!(MemoryVendor::from(0x10000), MemoryVendor::Unknown(0x10000));
+}
+
+#[test]
+fn test_nve_level_round_trip() {
+    for level in [
+        NveLevel::Debug,
+        NveLevel::Info,
+        NveLevel::Warning,
+        NveLevel::Error,
+        NveLevel::Null,
+        NveLevel::Unknown(5),
+        NveLevel::Unknown(-1),
+    ] {
+        assert_eq!(NveLevel::from(i32::from(level)), level);
+    }
+    assert_eq!(NveLevel::from(5), NveLevel::Unknown(5));
+}
+
+#[test]
+fn test_unexpected_compatibility_and_outband_state() {
+    assert_eq!(Compatibility::from(1), Compatibility::Ok);
+    assert_eq!(Compatibility::from(0), Compatibility::Unknown);
+    assert_eq!(Compatibility::from(42), Compatibility::Unknown);
+    assert_eq!(OutbandChannelState::from(1), OutbandChannelState::Normal);
+    assert_eq!(
+        OutbandChannelState::from(-1),
+        OutbandChannelState::Unknown(-1)
+    );
+}
+
+#[test]
+fn test_cpu_num_config_round_trip() {
+    let config = CpuNumConfig {
+        ctrl: 4,
+        aicpu: 6,
+        data: 2,
+    };
+    let buf = config.encode();
+    assert_eq!(&buf[..3], &[4, 2, 6]);
+    assert!(buf[3..].iter().all(|&byte| byte == 0));
+    assert_eq!(CpuNumConfig::decode(&buf), config);
+}
+
+#[test]
+fn test_cpu_num_config_decode_ignores_reserved() {
+    let mut buf = [0xffu8; CpuNumConfig::BUF_LEN];
+    buf[..3].copy_from_slice(&[1, 3, 7]);
+    let config = CpuNumConfig::decode(&buf);
+    assert_eq!(
+        config,
+        CpuNumConfig {
+            ctrl: 1,
+            aicpu: 7,
+            data: 3,
+        }
+    );
+    assert_eq!(config.total(), 11);
+}
+
+#[test]
+fn test_ts_fault_mask() {
+    let mask = TsFaultMask::from_bits_retain(0b0001) | TsFaultMask::from_bits_retain(0b1000);
+    assert_eq!(mask.bits(), 0b1001);
+    assert!(mask.contains(TsFaultMask::from_bits_retain(0b1000)));
+    assert!(mask.contains(TsFaultMask::from_bits_retain(0b0100)).not());
+    assert_eq!(TsFaultMask::from_bits_retain(1 << 31).bits(), 1 << 31);
+    assert!(TsFaultMask::default().is_empty());
+}